@@ -2,7 +2,7 @@ extern crate bm1397_protocol;
 
 use bm1397_protocol::command::{Command, Destination};
 use bm1397_protocol::register::{ChipAddress, Registers};
-use bm1397_protocol::response::{Response, ResponseType};
+use bm1397_protocol::response::Response;
 use std::time::Duration;
 
 fn main() {
@@ -23,10 +23,10 @@ fn main() {
     let mut resp: [u8; 9] = [0u8; 9];
     port.read_exact(&mut resp).expect("Found no data!");
     println!("<< {:x?}", resp);
-    let reg_resp = match Response::parse(&resp).expect("Error parsing") {
-        ResponseType::Reg(r) => r,
-        _ => panic!("Not a Reg Response"),
-    };
+    let reg_resp = Response::parse(&resp)
+        .expect("Error parsing")
+        .into_reg()
+        .expect("Not a Reg Response");
     let chip_addr = match reg_resp.register {
         Registers::ChipAddress(ca) => ca,
         _ => panic!("Not a ChipAddress Response"),
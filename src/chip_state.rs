@@ -0,0 +1,249 @@
+//! BM1397 Chip State.
+
+use crate::register::*;
+use crate::response::RegisterResponse;
+
+/// # Chip State
+///
+/// Aggregate the registers of a chip, one field per register, populated as the
+/// `RegisterResponse` arrive. A register that has not been read yet is `None`.
+///
+/// The field layout is stable, so with the `serde` feature a `ChipState` can be
+/// serialized to capture, diff and replay register dumps.
+///
+/// ## Example
+///
+/// ```
+/// use bm1397_protocol::chip_state::ChipState;
+/// use bm1397_protocol::register::{ChipAddress, Registers};
+///
+/// let mut state = ChipState::default();
+/// assert_eq!(state.chip_address, None);
+/// state.update(Registers::ChipAddress(ChipAddress::DEFAULT));
+/// assert_eq!(state.chip_address, Some(ChipAddress::DEFAULT));
+/// ```
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChipState {
+    pub chip_address: Option<ChipAddress>,
+    pub hash_rate: Option<HashRate>,
+    pub pll0_parameter: Option<PLL0Parameter>,
+    pub chip_nonce_offset: Option<ChipNonceOffset>,
+    pub hash_counting_number: Option<HashCountingNumber>,
+    pub ticket_mask: Option<TicketMask>,
+    pub misc_control: Option<MiscControl>,
+    pub i2c_control: Option<I2CControl>,
+    pub ordered_clock_enable: Option<OrderedClockEnable>,
+    pub fast_uart_configuration: Option<FastUARTConfiguration>,
+    pub uart_relay: Option<UARTRelay>,
+    pub ticket_mask2: Option<TicketMask2>,
+    pub core_register_control: Option<CoreRegisterControl>,
+    pub core_register_value: Option<CoreRegisterValue>,
+    pub external_temperature_sensor_read: Option<ExternalTemperatureSensorRead>,
+    pub error_flag: Option<ErrorFlag>,
+    pub nonce_error_counter: Option<NonceErrorCounter>,
+    pub nonce_overflow_counter: Option<NonceOverflowCounter>,
+    pub analog_mux_control: Option<AnalogMuxControl>,
+    pub io_driver_strenght_configuration: Option<IoDriverStrenghtConfiguration>,
+    pub time_out: Option<TimeOut>,
+    pub pll1_parameter: Option<PLL1Parameter>,
+    pub pll2_parameter: Option<PLL2Parameter>,
+    pub pll3_parameter: Option<PLL3Parameter>,
+    pub ordered_clock_monitor: Option<OrderedClockMonitor>,
+    pub pll0_divider: Option<PLL0Divider>,
+    pub pll1_divider: Option<PLL1Divider>,
+    pub pll2_divider: Option<PLL2Divider>,
+    pub pll3_divider: Option<PLL3Divider>,
+    pub clock_order_control0: Option<ClockOrderControl0>,
+    pub clock_order_control1: Option<ClockOrderControl1>,
+    pub clock_order_status: Option<ClockOrderStatus>,
+    pub frequency_sweep_control1: Option<FrequencySweepControl1>,
+    pub golden_nonce_for_sweep_return: Option<GoldenNonceForSweepReturn>,
+    pub returned_group_pattern_status: Option<ReturnedGroupPatternStatus>,
+    pub nonce_returned_timeout: Option<NonceReturnedTimeout>,
+    pub returned_single_pattern_status: Option<ReturnedSinglePatternStatus>,
+}
+
+impl ChipState {
+    /// ## Update a register.
+    ///
+    /// Store `register` in its matching field, replacing the previous value.
+    pub fn update(&mut self, register: Registers) {
+        match register {
+            Registers::ChipAddress(r) => self.chip_address = Some(r),
+            Registers::HashRate(r) => self.hash_rate = Some(r),
+            Registers::PLL0Parameter(r) => self.pll0_parameter = Some(r),
+            Registers::ChipNonceOffset(r) => self.chip_nonce_offset = Some(r),
+            Registers::HashCountingNumber(r) => self.hash_counting_number = Some(r),
+            Registers::TicketMask(r) => self.ticket_mask = Some(r),
+            Registers::MiscControl(r) => self.misc_control = Some(r),
+            Registers::I2CControl(r) => self.i2c_control = Some(r),
+            Registers::OrderedClockEnable(r) => self.ordered_clock_enable = Some(r),
+            Registers::FastUARTConfiguration(r) => self.fast_uart_configuration = Some(r),
+            Registers::UARTRelay(r) => self.uart_relay = Some(r),
+            Registers::TicketMask2(r) => self.ticket_mask2 = Some(r),
+            Registers::CoreRegisterControl(r) => self.core_register_control = Some(r),
+            Registers::CoreRegisterValue(r) => self.core_register_value = Some(r),
+            Registers::ExternalTemperatureSensorRead(r) => {
+                self.external_temperature_sensor_read = Some(r)
+            }
+            Registers::ErrorFlag(r) => self.error_flag = Some(r),
+            Registers::NonceErrorCounter(r) => self.nonce_error_counter = Some(r),
+            Registers::NonceOverflowCounter(r) => self.nonce_overflow_counter = Some(r),
+            Registers::AnalogMuxControl(r) => self.analog_mux_control = Some(r),
+            Registers::IoDriverStrenghtConfiguration(r) => {
+                self.io_driver_strenght_configuration = Some(r)
+            }
+            Registers::TimeOut(r) => self.time_out = Some(r),
+            Registers::PLL1Parameter(r) => self.pll1_parameter = Some(r),
+            Registers::PLL2Parameter(r) => self.pll2_parameter = Some(r),
+            Registers::PLL3Parameter(r) => self.pll3_parameter = Some(r),
+            Registers::OrderedClockMonitor(r) => self.ordered_clock_monitor = Some(r),
+            Registers::PLL0Divider(r) => self.pll0_divider = Some(r),
+            Registers::PLL1Divider(r) => self.pll1_divider = Some(r),
+            Registers::PLL2Divider(r) => self.pll2_divider = Some(r),
+            Registers::PLL3Divider(r) => self.pll3_divider = Some(r),
+            Registers::ClockOrderControl0(r) => self.clock_order_control0 = Some(r),
+            Registers::ClockOrderControl1(r) => self.clock_order_control1 = Some(r),
+            Registers::ClockOrderStatus(r) => self.clock_order_status = Some(r),
+            Registers::FrequencySweepControl1(r) => self.frequency_sweep_control1 = Some(r),
+            Registers::GoldenNonceForSweepReturn(r) => self.golden_nonce_for_sweep_return = Some(r),
+            Registers::ReturnedGroupPatternStatus(r) => {
+                self.returned_group_pattern_status = Some(r)
+            }
+            Registers::NonceReturnedTimeout(r) => self.nonce_returned_timeout = Some(r),
+            Registers::ReturnedSinglePatternStatus(r) => {
+                self.returned_single_pattern_status = Some(r)
+            }
+        }
+    }
+
+    /// ## Apply a register response.
+    ///
+    /// Store the register of `resp` in its matching field, see `ChipState::update`.
+    ///
+    /// The `chip_addr` of `resp` is not checked : feeding the responses of a whole chain
+    /// needs one `ChipState` per chip, keyed by `chip_addr`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    ///
+    /// use bm1397_protocol::chip_state::ChipState;
+    /// use bm1397_protocol::register::{ChipAddress, MiscControl};
+    /// use bm1397_protocol::response::{Response, ResponseType};
+    ///
+    /// let mut chain: BTreeMap<u8, ChipState> = BTreeMap::new();
+    /// for frame in [
+    ///     Response::encode_reg(0x00, &ChipAddress::DEFAULT),
+    ///     Response::encode_reg(0x08, &ChipAddress::DEFAULT),
+    ///     Response::encode_reg(0x08, &MiscControl::DEFAULT),
+    /// ] {
+    ///     if let Ok(ResponseType::Reg(resp)) = Response::parse(&frame) {
+    ///         chain.entry(resp.chip_addr).or_default().apply(&resp);
+    ///     }
+    /// }
+    /// assert_eq!(chain.len(), 2);
+    /// assert_eq!(chain[&0x08].misc_control, Some(MiscControl::DEFAULT));
+    /// assert_eq!(chain[&0x00].misc_control, None);
+    /// ```
+    pub fn apply(&mut self, resp: &RegisterResponse) {
+        self.update(resp.register);
+    }
+
+    /// ## Get the raw values.
+    ///
+    /// This returns the `(addr, value)` of each register, in the field order,
+    /// `None` for the registers not read yet.
+    fn raw_values(&self) -> [Option<(u8, u32)>; 37] {
+        [
+            self.chip_address.map(|r| (ChipAddress::ADDR, r.raw())),
+            self.hash_rate.map(|r| (HashRate::ADDR, r.raw())),
+            self.pll0_parameter.map(|r| (PLL0Parameter::ADDR, r.raw())),
+            self.chip_nonce_offset
+                .map(|r| (ChipNonceOffset::ADDR, r.raw())),
+            self.hash_counting_number
+                .map(|r| (HashCountingNumber::ADDR, r.raw())),
+            self.ticket_mask.map(|r| (TicketMask::ADDR, r.raw())),
+            self.misc_control.map(|r| (MiscControl::ADDR, r.raw())),
+            self.i2c_control.map(|r| (I2CControl::ADDR, r.raw())),
+            self.ordered_clock_enable
+                .map(|r| (OrderedClockEnable::ADDR, r.raw())),
+            self.fast_uart_configuration
+                .map(|r| (FastUARTConfiguration::ADDR, r.raw())),
+            self.uart_relay.map(|r| (UARTRelay::ADDR, r.raw())),
+            self.ticket_mask2.map(|r| (TicketMask2::ADDR, r.raw())),
+            self.core_register_control
+                .map(|r| (CoreRegisterControl::ADDR, r.raw())),
+            self.core_register_value
+                .map(|r| (CoreRegisterValue::ADDR, r.raw())),
+            self.external_temperature_sensor_read
+                .map(|r| (ExternalTemperatureSensorRead::ADDR, r.raw())),
+            self.error_flag.map(|r| (ErrorFlag::ADDR, r.raw())),
+            self.nonce_error_counter
+                .map(|r| (NonceErrorCounter::ADDR, r.raw())),
+            self.nonce_overflow_counter
+                .map(|r| (NonceOverflowCounter::ADDR, r.raw())),
+            self.analog_mux_control
+                .map(|r| (AnalogMuxControl::ADDR, r.raw())),
+            self.io_driver_strenght_configuration
+                .map(|r| (IoDriverStrenghtConfiguration::ADDR, r.raw())),
+            self.time_out.map(|r| (TimeOut::ADDR, r.raw())),
+            self.pll1_parameter.map(|r| (PLL1Parameter::ADDR, r.raw())),
+            self.pll2_parameter.map(|r| (PLL2Parameter::ADDR, r.raw())),
+            self.pll3_parameter.map(|r| (PLL3Parameter::ADDR, r.raw())),
+            self.ordered_clock_monitor
+                .map(|r| (OrderedClockMonitor::ADDR, r.raw())),
+            self.pll0_divider.map(|r| (PLL0Divider::ADDR, r.raw())),
+            self.pll1_divider.map(|r| (PLL1Divider::ADDR, r.raw())),
+            self.pll2_divider.map(|r| (PLL2Divider::ADDR, r.raw())),
+            self.pll3_divider.map(|r| (PLL3Divider::ADDR, r.raw())),
+            self.clock_order_control0
+                .map(|r| (ClockOrderControl0::ADDR, r.raw())),
+            self.clock_order_control1
+                .map(|r| (ClockOrderControl1::ADDR, r.raw())),
+            self.clock_order_status
+                .map(|r| (ClockOrderStatus::ADDR, r.raw())),
+            self.frequency_sweep_control1
+                .map(|r| (FrequencySweepControl1::ADDR, r.raw())),
+            self.golden_nonce_for_sweep_return
+                .map(|r| (GoldenNonceForSweepReturn::ADDR, r.raw())),
+            self.returned_group_pattern_status
+                .map(|r| (ReturnedGroupPatternStatus::ADDR, r.raw())),
+            self.nonce_returned_timeout
+                .map(|r| (NonceReturnedTimeout::ADDR, r.raw())),
+            self.returned_single_pattern_status
+                .map(|r| (ReturnedSinglePatternStatus::ADDR, r.raw())),
+        ]
+    }
+
+    /// ## Compare with the reset values.
+    ///
+    /// This returns an iterator over the `(addr, reset, current)` of each register read
+    /// whose value differs from its documented reset value (see `RESET_VALUES`),
+    /// in the field order. The registers not read yet are skipped.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::chip_state::ChipState;
+    /// use bm1397_protocol::register::{ChipAddress, MiscControl, Registers};
+    ///
+    /// let mut state = ChipState::default();
+    /// state.update(Registers::ChipAddress(ChipAddress::DEFAULT));
+    /// state.update(Registers::MiscControl(MiscControl::DEFAULT.set_rfs(true)));
+    /// assert!(state
+    ///     .diff_from_reset()
+    ///     .eq([(MiscControl::ADDR, MiscControl::RESET, 0x0000_7A01)]));
+    /// ```
+    pub fn diff_from_reset(&self) -> impl Iterator<Item = (u8, u32, u32)> {
+        self.raw_values()
+            .into_iter()
+            .flatten()
+            .filter_map(|(addr, current)| match reset_value(addr) {
+                Some(reset) if reset != current => Some((addr, reset, current)),
+                _ => None,
+            })
+    }
+}
@@ -4,29 +4,335 @@ use fugit::HertzU32;
 
 use crate::crc::{crc16, crc5};
 
-use crate::core_register::CoreRegister;
+use crate::core_register::{
+    ClockDelayCtrl, CoreEnable, CoreRegister, SweepClockCtrl, CORE_REGISTER_IDS,
+};
 use crate::register::{
-    CoreRegisterControl, FastUARTConfiguration, MiscControl, PLL3Parameter, Register, TicketMask,
+    pll0_for_frequency, CoreRegisterControl, ErrorFlag, FastUARTConfiguration,
+    FrequencySweepControl1, GoldenNonceForSweepReturn, I2CControl, MiscControl,
+    NonceReturnedTimeout, PLL3Parameter, Register, TicketMask, TicketMask2, WritableRegister,
 };
 use crate::specifier::BaudrateClockSelect;
+#[cfg(feature = "test-util")]
+use crate::{
+    register::Registers,
+    response::{Response, ResponseType},
+};
+use crate::{Error, CORE_COUNT};
 
 /// Some command can be send to All chip in the chain or to a specific one
+///
+/// The destination is encoded in the command opcode and the chip address byte :
+/// - `Destination::All` set the `ALL` bit (0x10) of the opcode and the chip address byte to `0x00`.
+///   Every chip on the chain execute the command, whatever its `ChipAddress`.
+/// - `Destination::Chip(addr)` keep the `ALL` bit cleared and set the chip address byte to `addr`.
+///   Only the chip with this `ChipAddress` execute the command, so `Destination::Chip(0)`
+///   is a unicast to the chip at address 0, not a broadcast.
+///
+/// ## Example
+///
+/// ```
+/// use bm1397_protocol::command::{Command, Destination};
+/// use bm1397_protocol::register::I2CControl;
+///
+/// // broadcast : opcode 0x52, chip address 0x00
+/// let cmd = Command::read_reg(I2CControl::default(), Destination::All);
+/// assert_eq!(cmd[2..5], [0x52, 0x05, 0x00]);
+///
+/// // unicast to chip 0 : opcode 0x42, chip address 0x00
+/// let cmd = Command::read_reg(I2CControl::default(), Destination::Chip(0));
+/// assert_eq!(cmd[2..5], [0x42, 0x05, 0x00]);
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum Destination {
+    /// All chips on the chain.
     All,
+    /// Only the chip with the given `ChipAddress`.
     Chip(u8),
 }
 
+impl From<u8> for Destination {
+    /// Get the `Destination::Chip` of a chip address.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination};
+    /// use bm1397_protocol::register::I2CControl;
+    ///
+    /// assert_eq!(Destination::from(0x40), Destination::Chip(0x40));
+    /// assert_eq!(
+    ///     Command::read_reg(I2CControl::default(), 0x40.into()),
+    ///     Command::read_reg(I2CControl::default(), Destination::Chip(0x40))
+    /// );
+    /// ```
+    fn from(addr: u8) -> Self {
+        Destination::Chip(addr)
+    }
+}
+
 pub type Midstate = [u8; 32];
 
+/// # Job Header
+///
+/// The block header fields sent in a job command, after the midstates were computed
+/// over the first 64 bytes of the header.
+///
+/// ## Byte order
+///
+/// The fields are the numeric values of the serialized block header fields, which are
+/// little-endian : `n_time` is the block timestamp, `n_bits` the compact target, and
+/// `merkle_root` the 4 last bytes of the serialized merkle root read as a little-endian `u32`.
+/// The job commands write them back little-endian, so the frame bytes are the block header
+/// bytes, unlike the register values which are written big-endian.
+///
+/// When starting from the serialized header, use `JobHeader::from_block_header` or
+/// `JobHeader::from_le_bytes` rather than building the `u32`s by hand.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct JobHeader {
+    pub n_bits: u32,
+    pub n_time: u32,
+    /// The last 4 bytes of the merkle root.
+    pub merkle_root: u32,
+}
+
+impl JobHeader {
+    /// Build a job header from the serialized (little-endian) bytes of each field,
+    /// as found in the block header.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::JobHeader;
+    ///
+    /// let header = JobHeader::from_le_bytes(
+    ///     [0x15, 0x9E, 0x07, 0x17],
+    ///     [0x75, 0x32, 0x8E, 0x63],
+    ///     [0xA2, 0xB3, 0x6A, 0x70],
+    /// );
+    /// assert_eq!(header.n_bits, 0x1707_9E15);
+    /// assert_eq!(header.n_time, 0x638E_3275);
+    /// assert_eq!(header.merkle_root, 0x706A_B3A2);
+    /// ```
+    pub const fn from_le_bytes(n_bits: [u8; 4], n_time: [u8; 4], merkle_root: [u8; 4]) -> Self {
+        Self {
+            n_bits: u32::from_le_bytes(n_bits),
+            n_time: u32::from_le_bytes(n_time),
+            merkle_root: u32::from_le_bytes(merkle_root),
+        }
+    }
+
+    /// Build a job header from a serialized 80 bytes block header, in block order :
+    /// the merkle root tail at `64..68`, `n_time` at `68..72` and `n_bits` at `72..76`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, JobHeader};
+    ///
+    /// let mut block_header = [0u8; 80];
+    /// block_header[64..76].copy_from_slice(&[
+    ///     0xA2, 0xB3, 0x6A, 0x70, 0x75, 0x32, 0x8E, 0x63, 0x15, 0x9E, 0x07, 0x17,
+    /// ]);
+    /// let header = JobHeader::from_block_header(&block_header);
+    /// assert_eq!(header.n_time, 0x638E_3275);
+    /// // the frame carry the block header bytes as is
+    /// let cmd: [u8; 56] = Command::job(0, &header, [&[0xAA; 32]]);
+    /// assert_eq!(cmd[10..14], block_header[72..76]);
+    /// assert_eq!(cmd[14..18], block_header[68..72]);
+    /// assert_eq!(cmd[18..22], block_header[64..68]);
+    /// ```
+    pub const fn from_block_header(header: &[u8; 80]) -> Self {
+        Self::from_le_bytes(
+            [header[72], header[73], header[74], header[75]],
+            [header[68], header[69], header[70], header[71]],
+            [header[64], header[65], header[66], header[67]],
+        )
+    }
+}
+
+/// # Parsed Job
+///
+/// The content of a job command, as returned by `Command::parse_job`.
+///
+/// `n_bits`, `n_time` and `merkle_root` are given back as passed to
+/// `Command::job_1_midstate` or `Command::job_4_midstate` : they are serialized
+/// little-endian in the frame, and decoded back the same way.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ParsedJob {
+    pub job_id: u8,
+    pub n_bits: u32,
+    pub n_time: u32,
+    pub merkle_root: u32,
+    /// Number of valid midstates in `midstates`.
+    pub midstate_count: u8,
+    pub midstates: [Midstate; 4],
+}
+
+impl ParsedJob {
+    /// The valid midstates of the job.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::Command;
+    ///
+    /// let cmd = Command::job_1_midstate(0, 0x1707_9E15, 0x638E_3275, 0x706A_B3A2, [&[0xAA; 32]]);
+    /// let job = Command::parse_job(&cmd).unwrap();
+    /// assert_eq!(job.midstates(), [[0xAA; 32]]);
+    /// ```
+    pub fn midstates(&self) -> &[Midstate] {
+        &self.midstates[..self.midstate_count as usize]
+    }
+
+    /// The header fields of the job.
+    pub const fn header(&self) -> JobHeader {
+        JobHeader {
+            n_bits: self.n_bits,
+            n_time: self.n_time,
+            merkle_root: self.merkle_root,
+        }
+    }
+
+    /// Check the header fields of the job against an expected `header`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, JobHeader};
+    ///
+    /// let header = JobHeader { n_bits: 0x1707_9E15, n_time: 0x638E_3275, merkle_root: 0x706A_B3A2 };
+    /// let cmd = Command::job_1_midstate(0, 0x1707_9E15, 0x638E_3275, 0x706A_B3A2, [&[0xAA; 32]]);
+    /// let job = Command::parse_job(&cmd).unwrap();
+    /// assert!(job.matches(&header));
+    /// assert!(!job.matches(&JobHeader { n_time: 0x7532_8E63, ..header }));
+    /// ```
+    pub fn matches(&self, header: &JobHeader) -> bool {
+        self.header() == *header
+    }
+}
+
+/// A command frame decoded by `Command::decode`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DecodedCommand {
+    /// `Command::set_chip_addr` with the new chip address.
+    SetChipAddr(u8),
+    /// `Command::write_reg` of the raw value `val` in the register at `addr`.
+    WriteReg {
+        dest: Destination,
+        addr: u8,
+        val: u32,
+    },
+    /// `Command::read_reg` of the register at `addr`.
+    ReadReg { dest: Destination, addr: u8 },
+    /// `Command::chain_inactive`.
+    ChainInactive,
+    /// A job, see `Command::parse_job`.
+    Job(ParsedJob),
+}
+
+/// # Ticket Config
+///
+/// The coherent set of registers filtering the nonces returned by the chips :
+/// - `ticket_mask` from the difficulty, see `TicketMask::from_difficulty`,
+/// - `ticket_mask2` left at its reset value, its use is not documented and
+///   no known init sequence set it,
+/// - `clock_delay` with the Multi Midstate mode (AsicBoost) matching the job midstate count.
+///
+/// With AsicBoost each midstate is a distinct block header (rolled version) and the
+/// ticket mask is checked against each one separately : the difficulty must *not*
+/// be divided by the midstate count. Doing so, or sending 4 midstates jobs with the
+/// Multi Midstate mode disabled, make the chips return low difficulty nonces.
+///
+/// ## Example
+///
+/// ```
+/// use bm1397_protocol::command::TicketConfig;
+/// use bm1397_protocol::core_register::ClockDelayCtrl;
+/// use bm1397_protocol::register::{TicketMask, TicketMask2};
+///
+/// let conf = TicketConfig::new(256, true, ClockDelayCtrl::DEFAULT);
+/// assert_eq!(conf.ticket_mask, TicketMask::from_difficulty(256));
+/// assert_eq!(conf.ticket_mask2, TicketMask2::DEFAULT);
+/// assert!(conf.clock_delay.multi_midstate_enabled());
+/// assert!(conf.midstate_count_ok(4));
+/// assert!(!conf.midstate_count_ok(1));
+///
+/// let conf = TicketConfig::new(256, false, ClockDelayCtrl::DEFAULT.enable_multi_midstate());
+/// assert!(!conf.clock_delay.multi_midstate_enabled());
+/// assert!(conf.midstate_count_ok(1));
+/// ```
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct TicketConfig {
+    pub ticket_mask: TicketMask,
+    pub ticket_mask2: TicketMask2,
+    pub clock_delay: ClockDelayCtrl,
+}
+
+impl TicketConfig {
+    /// Create the config for the difficulty `diff`, with AsicBoost enabled or not.
+    ///
+    /// Only the MMEN bit of `clock_delay` is modified, its other fields are kept as given.
+    pub fn new(diff: u32, asic_boost: bool, clock_delay: ClockDelayCtrl) -> Self {
+        Self {
+            ticket_mask: TicketMask::from_difficulty(diff),
+            ticket_mask2: TicketMask2::DEFAULT,
+            clock_delay: if asic_boost {
+                clock_delay.enable_multi_midstate()
+            } else {
+                clock_delay.disable_multi_midstate()
+            },
+        }
+    }
+
+    /// Check a job midstate count against the config, see `ClockDelayCtrl::midstate_count_ok`.
+    pub const fn midstate_count_ok(&self, midstate_count: u8) -> bool {
+        self.clock_delay.midstate_count_ok(midstate_count)
+    }
+}
+
 pub struct Command;
 
 impl Command {
-    const CMD_ALL_CHIP: u8 = 0x10;
-    const CMD_SEND_JOB: u8 = 0x21;
-    const CMD_SET_CHIP_ADDR: u8 = 0x40;
-    const CMD_WRITE_REGISTER: u8 = 0x41;
-    const CMD_READ_REGISTER: u8 = 0x42;
-    const CMD_CHAIN_INACTIVE: u8 = 0x43;
+    /// ## Broadcast bit of the opcode.
+    ///
+    /// The opcode byte (the third one of a frame, after the `0x55 0xAA` preamble) is built as :
+    /// - bits `[7:5]` : the frame type, `0b010` for a command, `0b001` for a job,
+    /// - bit `4` : this `ALL` bit, set for `Destination::All`,
+    /// - bits `[3:0]` : the command itself.
+    ///
+    /// So the broadcast opcode is `CMD_xxx + CMD_ALL_CHIP` (see `Command::opcode`).
+    pub const CMD_ALL_CHIP: u8 = 0x10;
+    /// ## Send Job opcode.
+    pub const CMD_SEND_JOB: u8 = 0x21;
+    /// ## Set Chip Address opcode, without the `ALL` bit.
+    pub const CMD_SET_CHIP_ADDR: u8 = 0x40;
+    /// ## Write Register opcode, without the `ALL` bit.
+    pub const CMD_WRITE_REGISTER: u8 = 0x41;
+    /// ## Read Register opcode, without the `ALL` bit.
+    pub const CMD_READ_REGISTER: u8 = 0x42;
+    /// ## Chain Inactive opcode, without the `ALL` bit.
+    pub const CMD_CHAIN_INACTIVE: u8 = 0x43;
+
+    /// # Opcode
+    ///
+    /// Get the opcode byte of the `cmd` command for `dest`, ie `cmd` with the
+    /// `CMD_ALL_CHIP` bit set for `Destination::All`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination};
+    ///
+    /// assert_eq!(Command::opcode(Command::CMD_READ_REGISTER, Destination::All), 0x52);
+    /// assert_eq!(Command::opcode(Command::CMD_READ_REGISTER, Destination::Chip(0)), 0x42);
+    /// ```
+    pub const fn opcode(cmd: u8, dest: Destination) -> u8 {
+        match dest {
+            Destination::All => cmd | Self::CMD_ALL_CHIP,
+            Destination::Chip(_) => cmd,
+        }
+    }
 
     /// # Chain Inactive Command
     ///
@@ -45,13 +351,14 @@ impl Command {
         let mut data: [u8; 7] = [
             0x55,
             0xAA,
-            Self::CMD_CHAIN_INACTIVE + Self::CMD_ALL_CHIP,
+            Self::opcode(Self::CMD_CHAIN_INACTIVE, Destination::All),
             5,
             0,
             0,
             0,
         ];
         data[6] = crc5(&data[2..6]);
+        trace!("chain_inactive: {:02X?}", data);
         data
     }
 
@@ -66,7 +373,7 @@ impl Command {
     /// This logical `ChipAddress` have 2 utilities :
     /// - sending command to a specific chip on the chain, using `Destination::Chip(ChipAddress)`.
     /// - when mining, the nonce space (u32) will be divided evenly according to `ChipAddress` :
-    /// each chip will add it's own `ChipAddress` to the MSB of the starting nonce for a job.
+    ///   each chip will add it's own `ChipAddress` to the MSB of the starting nonce for a job.
     ///
     /// ## Example
     ///
@@ -81,10 +388,56 @@ impl Command {
     /// ```
     pub fn set_chip_addr(addr: u8) -> [u8; 7] {
         let mut data: [u8; 7] = [0x55, 0xAA, Self::CMD_SET_CHIP_ADDR, 5, addr, 0, 0];
-        data[6] = crc5(&data[2..6]);
+        data[6] = Self::chip_addr_crc(addr);
+        trace!("set_chip_addr({}): {:02X?}", addr, data);
         data
     }
 
+    /// # Command CRC5
+    ///
+    /// Compute the CRC5 of a short command `frame` (not a job), ie the last byte of the frame.
+    ///
+    /// The CRC5 cover the bytes from the opcode to the last data byte : the `0x55 0xAA`
+    /// preamble and the CRC5 byte itself are excluded, so `frame` is the full frame and its
+    /// last byte is ignored. A `frame` shorter than 3 bytes gives the CRC5 of nothing.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination};
+    /// use bm1397_protocol::register::MiscControl;
+    ///
+    /// let cmd = Command::write_reg(MiscControl::from(0x0000_7A31), Destination::Chip(64));
+    /// assert_eq!(Command::command_crc5(&cmd), cmd[10]);
+    /// let cmd = Command::chain_inactive();
+    /// assert_eq!(Command::command_crc5(&cmd), cmd[6]);
+    /// ```
+    pub const fn command_crc5(frame: &[u8]) -> u8 {
+        match frame.len() {
+            0..=2 => crc5(&[]),
+            len => crc5(frame.split_at(len - 1).0.split_at(2).1),
+        }
+    }
+
+    /// # Set Chip Address CRC5
+    ///
+    /// Compute the CRC5 of `Command::set_chip_addr(addr)`, over `[0x40, 0x05, addr, 0x00]`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::Command;
+    ///
+    /// assert_eq!(Command::chip_addr_crc(0x00), 0x1C);
+    /// assert_eq!(Command::chip_addr_crc(0x08), 0x07);
+    /// // validate a received command
+    /// let cmd = [0x55, 0xAA, 0x40, 0x05, 0x08, 0x00, 0x07];
+    /// assert_eq!(cmd[6], Command::chip_addr_crc(cmd[4]));
+    /// ```
+    pub const fn chip_addr_crc(addr: u8) -> u8 {
+        crc5(&[Self::CMD_SET_CHIP_ADDR, 5, addr, 0])
+    }
+
     /// # Read Register Command
     ///
     /// Used to send a Read Register command on the chain.
@@ -111,15 +464,71 @@ impl Command {
     /// assert_eq!(cmd, [0x55, 0xAA, 0x42, 0x05, 0x40, 0x1C, 0x0B]);
     /// ```
     pub fn read_reg(reg: impl Register, dest: Destination) -> [u8; 7] {
-        let mut data: [u8; 7] = [0x55, 0xAA, Self::CMD_READ_REGISTER, 5, 0, reg.addr(), 0];
-        match dest {
-            Destination::All => data[2] += Self::CMD_ALL_CHIP,
-            Destination::Chip(c) => data[4] = c,
+        Self::read_reg_raw(reg.addr(), dest)
+    }
+
+    /// # Read Register by Address Command
+    ///
+    /// Same as `Command::read_reg`, but with the raw register address `addr`, for the
+    /// registers without a compile time type (generic tooling, registers not modeled
+    /// by this crate).
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination};
+    /// use bm1397_protocol::register::ChipAddress;
+    ///
+    /// assert_eq!(
+    ///     Command::read_reg_raw(0x00, Destination::All),
+    ///     Command::read_reg(ChipAddress::DEFAULT, Destination::All)
+    /// );
+    /// let cmd = Command::read_reg_raw(0xF0, Destination::Chip(8));
+    /// assert_eq!(cmd[6], Command::command_crc5(&cmd));
+    /// ```
+    pub fn read_reg_raw(addr: u8, dest: Destination) -> [u8; 7] {
+        let mut data: [u8; 7] = [
+            0x55,
+            0xAA,
+            Self::opcode(Self::CMD_READ_REGISTER, dest),
+            5,
+            0,
+            addr,
+            0,
+        ];
+        if let Destination::Chip(c) = dest {
+            data[4] = c;
         }
         data[6] = crc5(&data[2..6]);
+        trace!("read_reg({:#04x}, {:?}): {:02X?}", addr, dest, data);
         data
     }
 
+    /// # Expected Responses
+    ///
+    /// Number of response frames to collect after a `Command::read_reg` to `dest`,
+    /// on a chain of `chain_len` chips :
+    /// - `Destination::All` : every chip answer, so `chain_len` frames.
+    /// - `Destination::Chip(_)` : only the chip with this `ChipAddress` answer, so 1 frame
+    ///   (0 if the address is not assigned, which can only be detected by a timeout).
+    ///
+    /// Write commands do not get any response.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination};
+    ///
+    /// assert_eq!(Command::expected_responses(Destination::All, 12), 12);
+    /// assert_eq!(Command::expected_responses(Destination::Chip(8), 12), 1);
+    /// ```
+    pub const fn expected_responses(dest: Destination, chain_len: u8) -> usize {
+        match dest {
+            Destination::All => chain_len as usize,
+            Destination::Chip(_) => 1,
+        }
+    }
+
     /// # Write Register Command
     ///
     /// Used to send a Write Register command on the chain.
@@ -127,6 +536,8 @@ impl Command {
     /// All chips on the chain or only a specific one can be addressed by this command
     /// using the `dest` parameter.
     ///
+    /// Only `WritableRegister` can be given, status registers are rejected at compile time.
+    ///
     /// ## Example
     ///
     /// ```
@@ -141,29 +552,125 @@ impl Command {
     /// let cmd = Command::write_reg(MiscControl::from(0x0000_7A31), Destination::Chip(64));
     /// assert_eq!(cmd, [0x55, 0xAA, 0x41, 0x09, 0x40, 0x18, 0x00, 0x00, 0x7A, 0x31, 0x11]);
     /// ```
-    pub fn write_reg(reg: impl Register, dest: Destination) -> [u8; 11] {
+    pub fn write_reg(reg: impl WritableRegister, dest: Destination) -> [u8; 11] {
+        Self::write_reg_raw(reg.addr(), reg.val(), dest)
+    }
+
+    /// # Write Register by Address Command
+    ///
+    /// Same as `Command::write_reg`, but with the raw register address `addr` and `value`,
+    /// for the registers without a compile time type (generic tooling, registers not modeled
+    /// by this crate).
+    ///
+    /// There is no `WritableRegister` check : writing a status register is at best a no-op.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination};
+    /// use bm1397_protocol::register::MiscControl;
+    ///
+    /// assert_eq!(
+    ///     Command::write_reg_raw(0x18, 0x0000_7A31, Destination::Chip(64)),
+    ///     Command::write_reg(MiscControl::from(0x0000_7A31), Destination::Chip(64))
+    /// );
+    /// let cmd = Command::write_reg_raw(0xF0, 0x1234_5678, Destination::All);
+    /// assert_eq!(cmd[10], Command::command_crc5(&cmd));
+    /// ```
+    pub fn write_reg_raw(addr: u8, value: u32, dest: Destination) -> [u8; 11] {
         let mut data: [u8; 11] = [
             0x55,
             0xAA,
-            Self::CMD_WRITE_REGISTER,
+            Self::opcode(Self::CMD_WRITE_REGISTER, dest),
             9,
             0,
-            reg.addr(),
+            addr,
             0,
             0,
             0,
             0,
             0,
         ];
-        match dest {
-            Destination::All => data[2] += Self::CMD_ALL_CHIP,
-            Destination::Chip(c) => data[4] = c,
+        if let Destination::Chip(c) = dest {
+            data[4] = c;
         }
-        data[6..10].clone_from_slice(&reg.val().to_be_bytes());
+        data[6..10].clone_from_slice(&value.to_be_bytes());
         data[10] = crc5(&data[2..10]);
+        trace!(
+            "write_reg({:#04x}, {:#010x}, {:?}): {:02X?}",
+            addr,
+            value,
+            dest,
+            data
+        );
         data
     }
 
+    /// # Modify Register Command
+    ///
+    /// Used to send a Write Register command of `current`, the value just read from the chip,
+    /// transformed by `f`. As a write always cover the whole register, this is the
+    /// read-modify-write pattern to change some bits without clobbering the others.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination};
+    /// use bm1397_protocol::register::MiscControl;
+    ///
+    /// let current = MiscControl::from(0x0000_7A31);
+    /// let cmd = Command::modify_reg(current, |v| v ^ MiscControl::CORE_SRST_MASK, Destination::Chip(64));
+    /// assert_eq!(cmd, Command::write_reg(current.reset_core(), Destination::Chip(64)));
+    /// assert!(MiscControl::from(u32::from_be_bytes([cmd[6], cmd[7], cmd[8], cmd[9]])).core_srst());
+    /// ```
+    pub fn modify_reg<R, F>(current: R, f: F, dest: Destination) -> [u8; 11]
+    where
+        R: WritableRegister + From<u32>,
+        F: FnOnce(u32) -> u32,
+    {
+        Self::write_reg(R::from(f(current.val())), dest)
+    }
+
+    /// # Encode Write and Decode
+    ///
+    /// Test support : encode a Write Register command of `reg`, then decode the written
+    /// value back through `Response::parse`, as if the chip answered a read of it.
+    ///
+    /// This returns the decoded `Registers`.
+    ///
+    /// # Panics
+    ///
+    /// If the register address is unknown to `Response::parse`, or if the decoded value
+    /// differs from `reg.val()`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination};
+    /// use bm1397_protocol::register::{MiscControl, Registers};
+    ///
+    /// let misc = MiscControl::DEFAULT.set_bt8d(7);
+    /// assert_eq!(
+    ///     Command::encode_write_decode(misc, Destination::Chip(8)),
+    ///     Registers::MiscControl(misc)
+    /// );
+    /// ```
+    #[cfg(feature = "test-util")]
+    pub fn encode_write_decode(reg: impl WritableRegister, dest: Destination) -> Registers {
+        let (addr, val) = (reg.addr(), reg.val());
+        let cmd = Self::write_reg(reg, dest);
+        let mut frame = [
+            0xAA, 0x55, cmd[6], cmd[7], cmd[8], cmd[9], cmd[4], cmd[5], 0,
+        ];
+        frame[8] = Response::expected_crc5(&frame);
+        let register = match Response::parse(&frame) {
+            Ok(ResponseType::Reg(r)) => r.register,
+            other => panic!("write of {:#04x} not decoded: {:?}", addr, other),
+        };
+        assert_eq!(register.val(), val, "value not preserved");
+        register
+    }
+
     /// # Read Core Register Command
     ///
     /// Used to send a Read Core Register command on the chain.
@@ -186,6 +693,39 @@ impl Command {
         Self::write_reg(ctrl, dest)
     }
 
+    /// # Read All Core Registers Command
+    ///
+    /// Used to send a Read Core Register command for each core register of a core,
+    /// in the `CORE_REGISTER_IDS` order.
+    ///
+    /// The `CoreRegisterValue` responses can be decoded back with
+    /// `CoreRegisterValue::all_core_regs`, if given in the same order.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination};
+    /// use bm1397_protocol::core_register::SweepClockCtrl;
+    ///
+    /// // Read all core registers on core 0 of chip with ChipAddress@40
+    /// let mut cmds = Command::read_all_core_regs(0, Destination::Chip(40));
+    /// // HashClockCounter
+    /// assert_eq!(
+    ///     cmds.nth(6),
+    ///     Some([0x55, 0xAA, 0x41, 0x09, 0x28, 0x3C, 0x00, 0x00, 0x06, 0xff, 0x0A])
+    /// );
+    /// assert_eq!(
+    ///     cmds.next(),
+    ///     Some(Command::read_core_reg(0, SweepClockCtrl::default(), Destination::Chip(40)))
+    /// );
+    /// assert_eq!(cmds.next(), None);
+    /// ```
+    pub fn read_all_core_regs(core_id: u8, dest: Destination) -> impl Iterator<Item = [u8; 11]> {
+        CORE_REGISTER_IDS.iter().map(move |id| {
+            Self::write_reg(CoreRegisterControl::DEFAULT.read_id(core_id, *id), dest)
+        })
+    }
+
     /// # Write Core Register Command
     ///
     /// Used to send a Write Core Register command on the chain.
@@ -208,6 +748,295 @@ impl Command {
         Self::write_reg(ctrl, dest)
     }
 
+    /// # Write Core Register on All Cores Command
+    ///
+    /// Used to send a Write Core Register command to each core of the chip, core ids
+    /// from `0` to `CORE_COUNT - 1`.
+    ///
+    /// There is no documented all-cores `core_id` : `CoreRegisterControl` CORE_ID is 8 bits
+    /// and every value address a single core, `0xFF` included.
+    /// The 9 bits CORE_ID of `CoreRegisterValue` is only the id of the answering core.
+    /// So writing a core register to all cores still need one command per core,
+    /// but only one per chain when using `Destination::All`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination};
+    /// use bm1397_protocol::core_register::CoreEnable;
+    /// use bm1397_protocol::CORE_COUNT;
+    ///
+    /// let mut cmds = Command::write_core_reg_all_cores(CoreEnable::default(), Destination::All);
+    /// assert_eq!(
+    ///     cmds.next(),
+    ///     Some(Command::write_core_reg(0, CoreEnable::default(), Destination::All))
+    /// );
+    /// assert_eq!(cmds.count(), CORE_COUNT - 1);
+    /// ```
+    pub fn write_core_reg_all_cores(
+        reg: impl CoreRegister + Copy,
+        dest: Destination,
+    ) -> impl Iterator<Item = [u8; 11]> {
+        (0..CORE_COUNT as u8).map(move |core_id| Self::write_core_reg(core_id, reg, dest))
+    }
+
+    /// # Bring Up Core Command
+    ///
+    /// Used to send the Write Core Register commands enabling the `core_id` core :
+    /// 1. `clock_delay` with the hash clock enabled,
+    /// 2. `CoreEnable` with all the `CORE_EN_I` bits set.
+    ///
+    /// The hash clock frequency is not a per core setting : all the cores are fed by the
+    /// 16 ordered clocks, configured chip wide with the `PLLnParameter`, `PLLnDivider` and
+    /// `ClockOrderControl` registers, so it must be set with `Command::write_reg` before
+    /// bringing up the cores.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination};
+    /// use bm1397_protocol::core_register::{ClockDelayCtrl, CoreEnable};
+    ///
+    /// let mut cmds = Command::bring_up_core(3, ClockDelayCtrl::DEFAULT, Destination::Chip(8));
+    /// assert_eq!(
+    ///     cmds.next(),
+    ///     Some(Command::write_core_reg(
+    ///         3,
+    ///         ClockDelayCtrl::DEFAULT.enable_hash_clock(),
+    ///         Destination::Chip(8)
+    ///     ))
+    /// );
+    /// assert_eq!(
+    ///     cmds.next(),
+    ///     Some(Command::write_core_reg(
+    ///         3,
+    ///         CoreEnable::from(CoreEnable::CORE_EN_I_MASK),
+    ///         Destination::Chip(8)
+    ///     ))
+    /// );
+    /// assert_eq!(cmds.next(), None);
+    /// ```
+    pub fn bring_up_core(
+        core_id: u8,
+        clock_delay: ClockDelayCtrl,
+        dest: Destination,
+    ) -> impl Iterator<Item = [u8; 11]> {
+        [
+            Self::write_core_reg(core_id, clock_delay.enable_hash_clock(), dest),
+            Self::write_core_reg(core_id, CoreEnable::from(CoreEnable::CORE_EN_I_MASK), dest),
+        ]
+        .into_iter()
+    }
+
+    /// # Set Sweep Frequency Mode Command
+    ///
+    /// Used to send the Write Core Register commands setting the `SWPF_MODE` bit of both
+    /// `ClockDelayCtrl` and `SweepClockCtrl` of the `core_id` core to `enable`, in this order.
+    ///
+    /// The two core registers have their own `SWPF_MODE` bit which must agree,
+    /// the other fields of `clock_delay` and `sweep_clock` are kept as given.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination};
+    /// use bm1397_protocol::core_register::{ClockDelayCtrl, SweepClockCtrl};
+    ///
+    /// let cdc = ClockDelayCtrl::DEFAULT.enable_hash_clock();
+    /// let scc = SweepClockCtrl::DEFAULT.set_clk_sel(5);
+    /// let mut cmds = Command::set_sweep_frequency_mode(2, true, cdc, scc, Destination::All);
+    /// assert_eq!(
+    ///     cmds.next(),
+    ///     Some(Command::write_core_reg(
+    ///         2,
+    ///         cdc.enable_sweep_frequency_mode(),
+    ///         Destination::All
+    ///     ))
+    /// );
+    /// assert_eq!(
+    ///     cmds.next(),
+    ///     Some(Command::write_core_reg(
+    ///         2,
+    ///         scc.enable_sweep_frequency_mode(),
+    ///         Destination::All
+    ///     ))
+    /// );
+    /// assert_eq!(cmds.next(), None);
+    /// ```
+    pub fn set_sweep_frequency_mode(
+        core_id: u8,
+        enable: bool,
+        clock_delay: ClockDelayCtrl,
+        sweep_clock: SweepClockCtrl,
+        dest: Destination,
+    ) -> impl Iterator<Item = [u8; 11]> {
+        let (clock_delay, sweep_clock) = if enable {
+            (
+                clock_delay.enable_sweep_frequency_mode(),
+                sweep_clock.enable_sweep_frequency_mode(),
+            )
+        } else {
+            (
+                clock_delay.disable_sweep_frequency_mode(),
+                sweep_clock.disable_sweep_frequency_mode(),
+            )
+        };
+        [
+            Self::write_core_reg(core_id, clock_delay, dest),
+            Self::write_core_reg(core_id, sweep_clock, dest),
+        ]
+        .into_iter()
+    }
+
+    /// # Start Frequency Sweep Command
+    ///
+    /// Used to send the commands starting a frequency sweep, in this order :
+    /// 1. `timeout` in `NonceReturnedTimeout`,
+    /// 2. the sweep frequency mode on every core, see `Command::set_sweep_frequency_mode`,
+    /// 3. `sweep_ctrl` in `FrequencySweepControl1`.
+    ///
+    /// The sweep result is then polled with `Command::read_sweep_result` and decoded with
+    /// `GoldenNonceForSweepReturn::golden_nonce`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination};
+    /// use bm1397_protocol::core_register::{ClockDelayCtrl, SweepClockCtrl};
+    /// use bm1397_protocol::register::{FrequencySweepControl1, NonceReturnedTimeout};
+    /// use bm1397_protocol::CORE_COUNT;
+    ///
+    /// let timeout = NonceReturnedTimeout::DEFAULT.set_sweep_timeout(50_000);
+    /// let cmds: Vec<[u8; 11]> = Command::start_frequency_sweep(
+    ///     timeout,
+    ///     FrequencySweepControl1::DEFAULT,
+    ///     ClockDelayCtrl::DEFAULT.enable_hash_clock(),
+    ///     SweepClockCtrl::DEFAULT,
+    ///     Destination::All,
+    /// )
+    /// .collect();
+    /// assert_eq!(cmds.len(), 2 + 2 * CORE_COUNT);
+    /// assert_eq!(cmds[0], Command::write_reg(timeout, Destination::All));
+    /// assert_eq!(
+    ///     cmds[cmds.len() - 1],
+    ///     Command::write_reg(FrequencySweepControl1::DEFAULT, Destination::All)
+    /// );
+    /// ```
+    pub fn start_frequency_sweep(
+        timeout: NonceReturnedTimeout,
+        sweep_ctrl: FrequencySweepControl1,
+        clock_delay: ClockDelayCtrl,
+        sweep_clock: SweepClockCtrl,
+        dest: Destination,
+    ) -> impl Iterator<Item = [u8; 11]> {
+        core::iter::once(Self::write_reg(timeout, dest))
+            .chain((0..CORE_COUNT as u8).flat_map(move |core_id| {
+                Self::set_sweep_frequency_mode(core_id, true, clock_delay, sweep_clock, dest)
+            }))
+            .chain(core::iter::once(Self::write_reg(sweep_ctrl, dest)))
+    }
+
+    /// # Read Sweep Result Command
+    ///
+    /// Used to read `GoldenNonceForSweepReturn` once a frequency sweep started with
+    /// `Command::start_frequency_sweep` completed.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination};
+    /// use bm1397_protocol::register::GoldenNonceForSweepReturn;
+    ///
+    /// assert_eq!(
+    ///     Command::read_sweep_result(Destination::Chip(4)),
+    ///     Command::read_reg(GoldenNonceForSweepReturn::DEFAULT, Destination::Chip(4))
+    /// );
+    /// ```
+    pub fn read_sweep_result(dest: Destination) -> [u8; 7] {
+        Self::read_reg(GoldenNonceForSweepReturn::DEFAULT, dest)
+    }
+
+    /// # Read Error Flag Command
+    ///
+    /// Used to sample the `ErrorFlag` error counters.
+    ///
+    /// The BM1397 documentation does not say if CMD_ERR_CNT and WORK_ERR_CNT are
+    /// cleared on read, nor how to clear them, so no clearing write is sent.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination};
+    /// use bm1397_protocol::register::ErrorFlag;
+    ///
+    /// assert_eq!(
+    ///     Command::read_error_flag(Destination::All),
+    ///     Command::read_reg(ErrorFlag::DEFAULT, Destination::All)
+    /// );
+    /// let flag = ErrorFlag::from(0xFF03_0000);
+    /// assert_eq!(flag.cmd_err_cnt(), 0xFF);
+    /// assert_eq!(flag.work_err_cnt(), 3);
+    /// ```
+    pub fn read_error_flag(dest: Destination) -> [u8; 7] {
+        Self::read_reg(ErrorFlag::DEFAULT, dest)
+    }
+
+    /// # Start Temperature Read Command
+    ///
+    /// Used to start the read of the `temp_reg` register of the temperature sensor
+    /// at `i2c_addr` on the chip I2C bus.
+    ///
+    /// This is the first step of a temperature read, the second one is to read `I2CControl`
+    /// until it is not busy, and to decode it with `I2CControl::temperature`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination};
+    /// use bm1397_protocol::register::I2CControl;
+    ///
+    /// // Start reading the remote temperature (0x01) of the sensor at 0x4C, on chip with ChipAddress@40
+    /// let cmd = Command::start_temperature_read(0x4C, 0x01, Destination::Chip(40));
+    /// assert_eq!(cmd, Command::write_reg(I2CControl::DEFAULT.read(0x4C, 0x01), Destination::Chip(40)));
+    ///
+    /// // Then poll I2CControl
+    /// let cmd = Command::read_reg(I2CControl::DEFAULT, Destination::Chip(40));
+    /// assert_eq!(cmd[5], I2CControl::ADDR);
+    /// // until the chip answer a non busy I2CControl
+    /// let resp = I2CControl::from(0x0099_0132);
+    /// assert_eq!(resp.temperature(), Some(50));
+    /// ```
+    pub fn start_temperature_read(i2c_addr: u8, temp_reg: u8, dest: Destination) -> [u8; 11] {
+        Self::write_reg(I2CControl::DEFAULT.read(i2c_addr, temp_reg), dest)
+    }
+
+    /// # Soft Reset
+    ///
+    /// Reset the hashing cores by writing `MiscControl` with CORE_SRST set.
+    ///
+    /// The `current` `MiscControl` value is kept (only CORE_SRST is set) because
+    /// `MiscControl` also hold the UART baudrate settings : writing `MiscControl::DEFAULT`
+    /// would switch the chip(s) back to 115200 bauds.
+    /// It is not documented if CORE_SRST is self-clearing, so `current` should be
+    /// written back after the reset if the cores need to be released.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination};
+    /// use bm1397_protocol::register::MiscControl;
+    ///
+    /// let current = MiscControl::DEFAULT.set_bt8d(7);
+    /// let cmd = Command::soft_reset(current, Destination::All);
+    /// assert_eq!(cmd, Command::write_reg(current.reset_core(), Destination::All));
+    /// let misc = MiscControl::from(u32::from_be_bytes([cmd[6], cmd[7], cmd[8], cmd[9]]));
+    /// assert!(misc.core_srst());
+    /// assert_eq!(misc.bt8d(), 7);
+    /// ```
+    pub fn soft_reset(current: MiscControl, dest: Destination) -> [u8; 11] {
+        Self::write_reg(current.reset_core(), dest)
+    }
+
     /// Set the difficulty.
     ///
     /// Returns a command to set the difficulty.
@@ -224,6 +1053,42 @@ impl Command {
         Command::write_reg(TicketMask::from_difficulty(diff), dest)
     }
 
+    /// # Set Ticket Config Command
+    ///
+    /// Used to send the commands applying a `TicketConfig` :
+    /// 1. Write Register `TicketMask`,
+    /// 2. Write Register `TicketMask2`,
+    /// 3. Write Core Register `ClockDelayCtrl` to each core, see `write_core_reg_all_cores`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination, TicketConfig};
+    /// use bm1397_protocol::core_register::ClockDelayCtrl;
+    /// use bm1397_protocol::CORE_COUNT;
+    ///
+    /// let conf = TicketConfig::new(512, true, ClockDelayCtrl::DEFAULT.enable_hash_clock());
+    /// let mut cmds = Command::set_ticket_config(&conf, Destination::All);
+    /// assert_eq!(cmds.next(), Some(Command::write_reg(conf.ticket_mask, Destination::All)));
+    /// assert_eq!(cmds.next(), Some(Command::write_reg(conf.ticket_mask2, Destination::All)));
+    /// assert_eq!(
+    ///     cmds.next(),
+    ///     Some(Command::write_core_reg(0, conf.clock_delay, Destination::All))
+    /// );
+    /// assert_eq!(cmds.count(), CORE_COUNT - 1);
+    /// ```
+    pub fn set_ticket_config(
+        conf: &TicketConfig,
+        dest: Destination,
+    ) -> impl Iterator<Item = [u8; 11]> {
+        [
+            Self::write_reg(conf.ticket_mask, dest),
+            Self::write_reg(conf.ticket_mask2, dest),
+        ]
+        .into_iter()
+        .chain(Self::write_core_reg_all_cores(conf.clock_delay, dest))
+    }
+
     /// Set the baudrate.
     ///
     /// Returns a list of commands to set the baudrate.
@@ -274,6 +1139,503 @@ impl Command {
         }
     }
 
+    /// # Set Hash Frequency
+    ///
+    /// Used to send the Write Register commands setting PLL0 to `target`, computed by
+    /// `register::pll0_for_frequency` : `PLL0Divider` first, then `PLL0Parameter`.
+    ///
+    /// Returns `None` if `target` can not be reached.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination};
+    /// use bm1397_protocol::register::{PLL0Divider, PLL0Parameter};
+    /// use fugit::HertzU32;
+    ///
+    /// let cmds = Command::set_hash_frequency(HertzU32::MHz(25), HertzU32::MHz(400), Destination::All);
+    /// assert_eq!(
+    ///     cmds,
+    ///     Some([
+    ///         Command::write_reg(PLL0Divider::from(0x0F0F_0F00), Destination::All),
+    ///         Command::write_reg(PLL0Parameter::from(0x4060_0161), Destination::All),
+    ///     ])
+    /// );
+    /// ```
+    pub fn set_hash_frequency(
+        clki_freq: HertzU32,
+        target: HertzU32,
+        dest: Destination,
+    ) -> Option<[[u8; 11]; 2]> {
+        let (pll0, pll0_div) = pll0_for_frequency(clki_freq, target)?;
+        Some([Self::write_reg(pll0_div, dest), Self::write_reg(pll0, dest)])
+    }
+
+    /// # Baudrate Switch
+    ///
+    /// Build the commands switching the chain to `target` baudrate, and get the baudrate
+    /// the host UART must use once they are sent.
+    ///
+    /// The baudrate clock is `clki_freq` if `target` can be reached from it
+    /// (`target <= clki_freq / 8`), otherwise `pll3` divided by 7 (`PLL3_DIV4` at 6).
+    /// The commands are, in this order :
+    /// 1. `pll3` in `PLL3Parameter`,
+    /// 2. `FastUARTConfiguration` with `PLL3_DIV4` at 6,
+    /// 3. `MiscControl` with the `BCK_SEL` and `BT8D` fields, other fields at reset value.
+    ///
+    /// All the commands are sent at the old baudrate, the last one switching the chain
+    /// to the new baudrate : the host UART must switch right after it is sent, and before
+    /// anything else is sent or received.
+    ///
+    /// The returned baudrate is the one actually reached by the chain, which can differ
+    /// from `target` because of the `BT8D` rounding, see `MiscControl::chain_baudrate`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination};
+    /// use bm1397_protocol::register::PLL3Parameter;
+    /// use fugit::HertzU32;
+    ///
+    /// let pll3 = PLL3Parameter::DEFAULT.enable().lock().set_fbdiv(112);
+    /// let (cmds, baudrate) =
+    ///     Command::baudrate_switch(1_000_000, HertzU32::MHz(25), pll3, Destination::All);
+    /// assert_eq!(baudrate, 1_041_666);
+    /// assert_eq!(cmds[0], Command::write_reg(pll3, Destination::All));
+    /// assert_eq!(cmds[2][5], 0x18); // MiscControl last
+    ///
+    /// let (_, baudrate) =
+    ///     Command::baudrate_switch(6_250_000, HertzU32::MHz(25), pll3, Destination::All);
+    /// assert_eq!(baudrate, 6_250_000);
+    /// ```
+    pub fn baudrate_switch(
+        target: u32,
+        clki_freq: HertzU32,
+        pll3: PLL3Parameter,
+        dest: Destination,
+    ) -> ([[u8; 11]; 3], u32) {
+        let uart_conf = FastUARTConfiguration::DEFAULT.set_pll3_div4(6);
+        let pll3_freq = pll3.frequency(clki_freq);
+        let (bclk_sel, fbase) = if target <= clki_freq.raw() / 8 {
+            (BaudrateClockSelect::Clki, clki_freq.raw())
+        } else {
+            (
+                BaudrateClockSelect::Pll3,
+                pll3_freq.raw() / (uart_conf.pll3_div4() as u32 + 1),
+            )
+        };
+        let bt8d = (fbase / (8 * target.max(1))).saturating_sub(1).min(0x1FF);
+        let misc = MiscControl::DEFAULT
+            .set_bclk_sel(bclk_sel)
+            .set_bt8d(bt8d as u16);
+        (
+            [
+                Self::write_reg(pll3, dest),
+                Self::write_reg(uart_conf, dest),
+                Self::write_reg(misc, dest),
+            ],
+            misc.chain_baudrate(&uart_conf, clki_freq, pll3_freq),
+        )
+    }
+
+    /// # Job Length
+    ///
+    /// The length byte of a job command with `midstate_count` midstates :
+    /// the number of bytes of the frame following the preamble, CRC16 included.
+    ///
+    /// Only 1 and 4 midstates are used by `Command::job_1_midstate` and
+    /// `Command::job_4_midstate`.
+    ///
+    /// ## Return
+    /// - `None` if `midstate_count` is greater than 7, the length would not fit in a byte.
+    /// - `Some(u8)` with the length byte.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::Command;
+    ///
+    /// assert_eq!(Command::job_length(1), Some(0x36));
+    /// assert_eq!(Command::job_length(4), Some(0x96));
+    /// assert_eq!(Command::job_length(7), Some(0xF6));
+    /// assert_eq!(Command::job_length(8), None);
+    /// ```
+    pub const fn job_length(midstate_count: u8) -> Option<u8> {
+        match midstate_count {
+            0..=7 => Some(22 + midstate_count * 32),
+            _ => None,
+        }
+    }
+
+    /// # Job Frame Size
+    ///
+    /// The full on-wire size in bytes of a job command with `midstate_count` midstates,
+    /// preamble and CRC16 included, ie `Command::job_length(midstate_count) + 2`.
+    ///
+    /// This is the runtime counterpart of `Command::job_len`, to budget the UART time
+    /// of the jobs. Only 1 to 4 midstates are accepted by the chip.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::Command;
+    ///
+    /// assert_eq!(Command::job_frame_size(1), 56);
+    /// assert_eq!(Command::job_frame_size(2), 88);
+    /// assert_eq!(Command::job_frame_size(3), 120);
+    /// assert_eq!(Command::job_frame_size(4), 152);
+    /// assert_eq!(Command::job_frame_size(4), Command::job_len::<4>());
+    /// ```
+    pub const fn job_frame_size(midstate_count: u8) -> usize {
+        2 + 22 + midstate_count as usize * 32
+    }
+
+    /// # Job CRC
+    ///
+    /// The CRC16 of a job command `frame`, computed over the payload, between the
+    /// 2 bytes preamble and the 2 bytes CRC16 at the end of the `frame`.
+    ///
+    /// The `frame` must be the full job command, the CRC16 bytes are not used
+    /// so it can be validated against them.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::Command;
+    ///
+    /// let cmd = Command::job_1_midstate(0, 0x1707_9E15, 0x638E_3275, 0x706A_B3A2, [&[0; 32]]);
+    /// let crc = Command::job_crc(&cmd);
+    /// assert_eq!(crc.to_be_bytes(), cmd[cmd.len() - 2..]);
+    /// ```
+    pub fn job_crc(frame: &[u8]) -> u16 {
+        crc16(frame.get(2..frame.len().saturating_sub(2)).unwrap_or(&[]))
+    }
+
+    /// # Parse Job Command
+    ///
+    /// Parse a job command `frame`, as built by `Command::job_1_midstate` or
+    /// `Command::job_4_midstate`.
+    ///
+    /// ## Return
+    /// - `Err(Error::InvalidPreamble)` if it first 2 bytes are not `[0x55, 0xAA]`.
+    /// - `Err(Error::UnknownCommand(u8))` with the command byte if it is not a job command.
+    /// - `Err(Error::InvalidMidstateCount(usize))` with the midstate count byte if it is not
+    ///   1, 2, 3 or 4.
+    /// - `Err(Error::InvalidLength(usize))` with the frame length if it do not match the
+    ///   length byte and the midstate count.
+    /// - `Err(Error::InvalidCrc16 { expected, found })` if the CRC16 is not valid.
+    /// - `Ok(ParsedJob)` with the job content.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Midstate};
+    /// use bm1397_protocol::Error;
+    ///
+    /// let midstates: [&Midstate; 4] = [&[0; 32], &[1; 32], &[2; 32], &[3; 32]];
+    /// let mut cmd = Command::job_4_midstate(7, 0x1707_9E15, 0x638E_3275, 0x706A_B3A2, midstates);
+    /// let job = Command::parse_job(&cmd).unwrap();
+    /// assert_eq!(job.job_id, 7);
+    /// assert_eq!(job.n_bits, 0x1707_9E15);
+    /// assert_eq!(job.n_time, 0x638E_3275);
+    /// assert_eq!(job.merkle_root, 0x706A_B3A2);
+    /// assert_eq!(job.midstates(), [[0; 32], [1; 32], [2; 32], [3; 32]]);
+    ///
+    /// // Error::InvalidLength
+    /// assert_eq!(Command::parse_job(&cmd[..56]), Err(Error::InvalidLength(56)));
+    ///
+    /// // Error::InvalidMidstateCount
+    /// let mut bad = cmd;
+    /// bad[5] = 5;
+    /// assert_eq!(Command::parse_job(&bad), Err(Error::InvalidMidstateCount(5)));
+    ///
+    /// // Error::InvalidCrc16
+    /// cmd[30] ^= 0xFF;
+    /// assert!(matches!(Command::parse_job(&cmd), Err(Error::InvalidCrc16 { .. })));
+    ///
+    /// // Error::InvalidPreamble
+    /// cmd[0] = 0xAA;
+    /// cmd[1] = 0x55;
+    /// assert_eq!(Command::parse_job(&cmd), Err(Error::InvalidPreamble));
+    /// ```
+    pub fn parse_job(frame: &[u8]) -> Result<ParsedJob, Error> {
+        if frame.len() < 2 || frame[0] != 0x55 || frame[1] != 0xAA {
+            return Err(Error::InvalidPreamble);
+        }
+        if frame.len() < 6 {
+            return Err(Error::InvalidLength(frame.len()));
+        }
+        if frame[2] != Self::CMD_SEND_JOB {
+            return Err(Error::UnknownCommand(frame[2]));
+        }
+        let midstate_count = Self::check_midstate_count(frame[5] as usize)?;
+        if Self::job_length(midstate_count) != Some(frame[3])
+            || frame.len() != frame[3] as usize + 2
+        {
+            return Err(Error::InvalidLength(frame.len()));
+        }
+        let offset = frame.len() - 2;
+        let expected = Self::job_crc(frame);
+        let found = u16::from_be_bytes([frame[offset], frame[offset + 1]]);
+        if expected != found {
+            return Err(Error::InvalidCrc16 { expected, found });
+        }
+        let word =
+            |i: usize| u32::from_le_bytes([frame[i], frame[i + 1], frame[i + 2], frame[i + 3]]);
+        let mut midstates: [Midstate; 4] = [[0; 32]; 4];
+        for (ms, chunk) in midstates.iter_mut().zip(frame[22..offset].chunks_exact(32)) {
+            ms.clone_from_slice(chunk);
+        }
+        Ok(ParsedJob {
+            job_id: frame[4],
+            n_bits: word(10),
+            n_time: word(14),
+            merkle_root: word(18),
+            midstate_count,
+            midstates,
+        })
+    }
+
+    /// # Decode Command
+    ///
+    /// Decode a command `frame`, as built by the `Command` functions, jobs included.
+    ///
+    /// The command preamble is `[0x55, 0xAA]`, the opposite order of the responses one
+    /// (`[0xAA, 0x55]`) : a response frame given here is rejected.
+    ///
+    /// ## Return
+    /// - `Err(Error::InvalidPreamble)` if it first 2 bytes are not `[0x55, 0xAA]`.
+    /// - `Err(Error::UnknownCommand(u8))` with the opcode if it is not a known command.
+    /// - `Err(Error::InvalidLength(usize))` with the frame length if it do not match the
+    ///   command or its length byte.
+    /// - `Err(Error::InvalidCrc5 { expected, found })` if the CRC5 is not valid.
+    /// - the errors of `Command::parse_job` for a job.
+    /// - `Ok(DecodedCommand)` with the command content.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, DecodedCommand, Destination};
+    /// use bm1397_protocol::register::{MiscControl, Register};
+    /// use bm1397_protocol::Error;
+    ///
+    /// let cmd = Command::write_reg(MiscControl::from(0x0000_7A31), Destination::Chip(64));
+    /// assert_eq!(
+    ///     Command::decode(&cmd),
+    ///     Ok(DecodedCommand::WriteReg { dest: Destination::Chip(64), addr: MiscControl::ADDR, val: 0x0000_7A31 })
+    /// );
+    /// let cmd = Command::read_reg(MiscControl::DEFAULT, Destination::All);
+    /// assert_eq!(
+    ///     Command::decode(&cmd),
+    ///     Ok(DecodedCommand::ReadReg { dest: Destination::All, addr: MiscControl::ADDR })
+    /// );
+    /// assert_eq!(Command::decode(&Command::set_chip_addr(8)), Ok(DecodedCommand::SetChipAddr(8)));
+    /// assert_eq!(Command::decode(&Command::chain_inactive()), Ok(DecodedCommand::ChainInactive));
+    ///
+    /// // Error::InvalidPreamble : a response frame, with the response preamble order
+    /// let resp = [0xAA, 0x55, 0x13, 0x97, 0x18, 0x00, 0x00, 0x00, 0x06];
+    /// assert_eq!(Command::decode(&resp), Err(Error::InvalidPreamble));
+    ///
+    /// // Error::InvalidCrc5
+    /// let mut cmd = Command::chain_inactive();
+    /// cmd[6] ^= 0x01;
+    /// assert_eq!(Command::decode(&cmd), Err(Error::InvalidCrc5 { expected: 0x03, found: 0x02 }));
+    ///
+    /// // Error::InvalidLength
+    /// assert_eq!(Command::decode(&cmd[..6]), Err(Error::InvalidLength(6)));
+    /// ```
+    pub fn decode(frame: &[u8]) -> Result<DecodedCommand, Error> {
+        if frame.len() < 2 || frame[0] != 0x55 || frame[1] != 0xAA {
+            return Err(Error::InvalidPreamble);
+        }
+        if frame.len() < 3 {
+            return Err(Error::InvalidLength(frame.len()));
+        }
+        if frame[2] == Self::CMD_SEND_JOB {
+            return Self::parse_job(frame).map(DecodedCommand::Job);
+        }
+        let len = match frame[2] & !Self::CMD_ALL_CHIP {
+            Self::CMD_WRITE_REGISTER => 11,
+            Self::CMD_SET_CHIP_ADDR | Self::CMD_READ_REGISTER | Self::CMD_CHAIN_INACTIVE => 7,
+            _ => return Err(Error::UnknownCommand(frame[2])),
+        };
+        if frame.len() != len || frame[3] as usize != len - 2 {
+            return Err(Error::InvalidLength(frame.len()));
+        }
+        let expected = Self::command_crc5(frame);
+        if frame[len - 1] != expected {
+            return Err(Error::InvalidCrc5 {
+                expected,
+                found: frame[len - 1],
+            });
+        }
+        let dest = if frame[2] & Self::CMD_ALL_CHIP == Self::CMD_ALL_CHIP {
+            Destination::All
+        } else {
+            Destination::Chip(frame[4])
+        };
+        Ok(match frame[2] & !Self::CMD_ALL_CHIP {
+            Self::CMD_SET_CHIP_ADDR => DecodedCommand::SetChipAddr(frame[4]),
+            Self::CMD_READ_REGISTER => DecodedCommand::ReadReg {
+                dest,
+                addr: frame[5],
+            },
+            Self::CMD_CHAIN_INACTIVE => DecodedCommand::ChainInactive,
+            _ => DecodedCommand::WriteReg {
+                dest,
+                addr: frame[5],
+                val: u32::from_be_bytes([frame[6], frame[7], frame[8], frame[9]]),
+            },
+        })
+    }
+
+    /// # Raw Job Command
+    ///
+    /// Build a job command in `buf` with an explicit midstate count byte, that can differ
+    /// from the number of `midstates` : this is used to craft malformed jobs on purpose,
+    /// to test a receiver. The length byte and the CRC16 always match the frame actually
+    /// built, only the midstate count byte is `declared_count`.
+    ///
+    /// ## Return
+    /// - `Err(Error::InvalidMidstateCount(usize))` with the number of midstates if there are
+    ///   more than 7 (the length byte would overflow).
+    /// - `Err(Error::InvalidLength(usize))` with the `buf` length if it is smaller than the frame.
+    /// - `Ok(usize)` with the frame length, the frame being `buf[..len]`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, JobHeader};
+    /// use bm1397_protocol::Error;
+    ///
+    /// let header = JobHeader { n_bits: 0x1707_9E15, n_time: 0x638E_3275, merkle_root: 0x706A_B3A2 };
+    /// let mut buf = [0u8; 152];
+    ///
+    /// // same as job_1_midstate
+    /// let len = Command::job_raw(0, &header, &[[0xAA; 32]], 1, &mut buf).unwrap();
+    /// assert_eq!(
+    ///     buf[..len],
+    ///     Command::job_1_midstate(0, 0x1707_9E15, 0x638E_3275, 0x706A_B3A2, [&[0xAA; 32]])
+    /// );
+    ///
+    /// // 1 midstate declared as 4
+    /// let len = Command::job_raw(0, &header, &[[0xAA; 32]], 4, &mut buf).unwrap();
+    /// assert_eq!(buf[5], 4);
+    /// assert_eq!(Command::parse_job(&buf[..len]), Err(Error::InvalidLength(56)));
+    ///
+    /// // too many midstates
+    /// assert_eq!(
+    ///     Command::job_raw(0, &header, &[[0xAA; 32]; 8], 8, &mut buf),
+    ///     Err(Error::InvalidMidstateCount(8))
+    /// );
+    ///
+    /// // buffer too small
+    /// assert_eq!(
+    ///     Command::job_raw(0, &header, &[[0xAA; 32]; 4], 4, &mut buf[..56]),
+    ///     Err(Error::InvalidLength(56))
+    /// );
+    /// ```
+    pub fn job_raw(
+        job_id: u8,
+        header: &JobHeader,
+        midstates: &[Midstate],
+        declared_count: u8,
+        buf: &mut [u8],
+    ) -> Result<usize, Error> {
+        let length = u8::try_from(midstates.len())
+            .ok()
+            .and_then(Self::job_length)
+            .ok_or(Error::InvalidMidstateCount(midstates.len()))?;
+        let len = Self::job_frame_size(midstates.len() as u8);
+        let buf_len = buf.len();
+        let data = buf.get_mut(..len).ok_or(Error::InvalidLength(buf_len))?;
+        data.fill(0);
+        data[0] = 0x55;
+        data[1] = 0xAA;
+        data[2] = Self::CMD_SEND_JOB;
+        data[3] = length;
+        data[4] = job_id;
+        data[5] = declared_count;
+        data[10..14].clone_from_slice(&header.n_bits.to_le_bytes());
+        data[14..18].clone_from_slice(&header.n_time.to_le_bytes());
+        data[18..22].clone_from_slice(&header.merkle_root.to_le_bytes());
+        for (chunk, ms) in data[22..len - 2].chunks_exact_mut(32).zip(midstates) {
+            chunk.clone_from_slice(ms);
+        }
+        let crc = Self::job_crc(data);
+        data[len - 2..].clone_from_slice(&crc.to_be_bytes());
+        trace!("job_raw({}): {:02X?}", job_id, data);
+        Ok(len)
+    }
+
+    /// # Check Midstate Count
+    ///
+    /// Check the number of midstates of a job, the chip only accept 1 to 4 midstates.
+    ///
+    /// ## Return
+    /// - `Err(Error::InvalidMidstateCount(usize))` with `count` if it is not 1, 2, 3 or 4.
+    /// - `Ok(u8)` with `count`, as the job midstate count byte.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::Command;
+    /// use bm1397_protocol::Error;
+    ///
+    /// assert_eq!(Command::check_midstate_count(4), Ok(4));
+    /// assert_eq!(Command::check_midstate_count(0), Err(Error::InvalidMidstateCount(0)));
+    /// assert_eq!(Command::check_midstate_count(5), Err(Error::InvalidMidstateCount(5)));
+    /// ```
+    pub const fn check_midstate_count(count: usize) -> Result<u8, Error> {
+        match count {
+            1..=4 => Ok(count as u8),
+            _ => Err(Error::InvalidMidstateCount(count)),
+        }
+    }
+
+    /// # Job from a Midstates Slice Command
+    ///
+    /// Build a job command in `buf` from a slice of `midstates`, the midstate count byte
+    /// being the slice length. Unlike `Command::job_raw`, the count is checked.
+    ///
+    /// ## Return
+    /// - `Err(Error::InvalidMidstateCount(usize))` with the number of midstates if it is not
+    ///   1, 2, 3 or 4, see `Command::check_midstate_count`.
+    /// - `Err(Error::InvalidLength(usize))` with the `buf` length if it is smaller than the frame.
+    /// - `Ok(usize)` with the frame length, the frame being `buf[..len]`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, JobHeader};
+    /// use bm1397_protocol::Error;
+    ///
+    /// let header = JobHeader { n_bits: 0x1707_9E15, n_time: 0x638E_3275, merkle_root: 0x706A_B3A2 };
+    /// let mut buf = [0u8; 152];
+    ///
+    /// let len = Command::job_from_slice(0, &header, &[[0xAA; 32]; 4], &mut buf).unwrap();
+    /// assert_eq!(len, 152);
+    /// assert_eq!(Command::parse_job(&buf[..len]).unwrap().midstate_count, 4);
+    ///
+    /// assert_eq!(
+    ///     Command::job_from_slice(0, &header, &[[0xAA; 32]; 5], &mut buf),
+    ///     Err(Error::InvalidMidstateCount(5))
+    /// );
+    /// assert_eq!(
+    ///     Command::job_from_slice(0, &header, &[], &mut buf),
+    ///     Err(Error::InvalidMidstateCount(0))
+    /// );
+    /// ```
+    pub fn job_from_slice(
+        job_id: u8,
+        header: &JobHeader,
+        midstates: &[Midstate],
+        buf: &mut [u8],
+    ) -> Result<usize, Error> {
+        let count = Self::check_midstate_count(midstates.len())?;
+        Self::job_raw(job_id, header, midstates, count, buf)
+    }
+
     /// # Job with 1 Midstate Command
     ///
     /// ## Example
@@ -306,26 +1668,12 @@ impl Command {
         merkle_root: u32,
         midstates: [&Midstate; 1],
     ) -> [u8; 56] {
-        let mut data: [u8; 56] = [0; 56];
-        data[0] = 0x55;
-        data[1] = 0xAA;
-        data[2] = Self::CMD_SEND_JOB;
-        // data[3] = 22 + (midstates.len() * 32) as u8;
-        data[3] = data.len() as u8 - 2;
-        data[4] = job_id;
-        data[5] = midstates.len() as u8;
-        // data[6..].clone_from_slice(&0u32.to_le_bytes()); // starting_nonce ?
-        data[10..14].clone_from_slice(&n_bits.to_le_bytes());
-        data[14..18].clone_from_slice(&n_time.to_le_bytes());
-        data[18..22].clone_from_slice(&merkle_root.to_le_bytes());
-        let mut offset = 22;
-        for ms in midstates.into_iter() {
-            data[offset..offset + ms.len()].clone_from_slice(ms);
-            offset += ms.len();
-        }
-        let crc = crc16(&data[2..offset]);
-        data[offset..offset + 2].clone_from_slice(&crc.to_be_bytes());
-        data
+        let header = JobHeader {
+            n_bits,
+            n_time,
+            merkle_root,
+        };
+        Self::job(job_id, &header, midstates)
     }
 
     /// # Job with 4 Midstate Command
@@ -382,25 +1730,89 @@ impl Command {
         merkle_root: u32,
         midstates: [&Midstate; 4],
     ) -> [u8; 152] {
-        let mut data: [u8; 152] = [0; 152];
+        let header = JobHeader {
+            n_bits,
+            n_time,
+            merkle_root,
+        };
+        Self::job(job_id, &header, midstates)
+    }
+
+    /// # Job Frame Length
+    ///
+    /// The length in bytes of a job command with `N` midstates, preamble and CRC16 included,
+    /// ie `Command::job_length(N) + 2`. It is computed in `usize`, so it do not overflow
+    /// for large `N`, `Command::job` rejecting more than 7 midstates at compile time.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::Command;
+    ///
+    /// assert_eq!(Command::job_len::<1>(), 56);
+    /// assert_eq!(Command::job_len::<4>(), 152);
+    /// assert_eq!(Command::job_len::<256>(), 8216);
+    /// ```
+    pub const fn job_len<const N: usize>() -> usize {
+        2 + 22 + N * 32
+    }
+
+    /// # Job with N Midstates Command
+    ///
+    /// Build a job command with `N` midstates in a `[u8; L]` array, `L` being
+    /// `Command::job_len::<N>()`. As `L` can not be computed from `N` on stable Rust,
+    /// it is given explicitly or inferred from the destination type, and checked at compile
+    /// time : a wrong `L` or more than 7 midstates do not build.
+    ///
+    /// `Command::job_1_midstate` and `Command::job_4_midstate` are this with 1 and 4 midstates.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, JobHeader};
+    ///
+    /// let header = JobHeader { n_bits: 0x1707_9E15, n_time: 0x638E_3275, merkle_root: 0x706A_B3A2 };
+    /// let cmd: [u8; Command::job_len::<2>()] = Command::job(0, &header, [&[0xAA; 32], &[0x55; 32]]);
+    /// assert_eq!(Command::job_length(2), Some(cmd[3]));
+    /// let job = Command::parse_job(&cmd).unwrap();
+    /// assert_eq!(job.midstates(), [[0xAA; 32], [0x55; 32]]);
+    /// assert!(job.matches(&header));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use bm1397_protocol::command::{Command, JobHeader};
+    ///
+    /// let cmd: [u8; 56] = Command::job(0, &JobHeader::default(), [&[0xAA; 32]; 2]);
+    /// ```
+    pub fn job<const N: usize, const L: usize>(
+        job_id: u8,
+        header: &JobHeader,
+        midstates: [&Midstate; N],
+    ) -> [u8; L] {
+        const {
+            assert!(N <= 7, "a job can not have more than 7 midstates");
+            assert!(
+                L == Self::job_len::<N>(),
+                "L must be Command::job_len::<N>()"
+            );
+        }
+        let mut data: [u8; L] = [0; L];
         data[0] = 0x55;
         data[1] = 0xAA;
         data[2] = Self::CMD_SEND_JOB;
-        // data[3] = 22 + (midstates.len() * 32) as u8;
-        data[3] = data.len() as u8 - 2;
+        data[3] = (L - 2) as u8;
         data[4] = job_id;
-        data[5] = midstates.len() as u8;
+        data[5] = N as u8;
         // data[6..].clone_from_slice(&0u32.to_le_bytes()); // starting_nonce ?
-        data[10..14].clone_from_slice(&n_bits.to_le_bytes());
-        data[14..18].clone_from_slice(&n_time.to_le_bytes());
-        data[18..22].clone_from_slice(&merkle_root.to_le_bytes());
-        let mut offset = 22;
-        for ms in midstates.into_iter() {
-            data[offset..offset + ms.len()].clone_from_slice(ms);
-            offset += ms.len();
+        data[10..14].clone_from_slice(&header.n_bits.to_le_bytes());
+        data[14..18].clone_from_slice(&header.n_time.to_le_bytes());
+        data[18..22].clone_from_slice(&header.merkle_root.to_le_bytes());
+        for (chunk, ms) in data[22..L - 2].chunks_exact_mut(32).zip(midstates) {
+            chunk.clone_from_slice(ms);
         }
-        let crc = crc16(&data[2..offset]);
-        data[offset..offset + 2].clone_from_slice(&crc.to_be_bytes());
+        let crc = Self::job_crc(&data);
+        data[L - 2..].clone_from_slice(&crc.to_be_bytes());
+        trace!("job::<{}>({}): {:02X?}", N, job_id, data);
         data
     }
 }
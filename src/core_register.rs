@@ -21,6 +21,21 @@ macro_rules! impl_boilerplate_for {
             }
         }
 
+        impl $REG {
+            /// ## Get the raw value.
+            ///
+            /// This is the same as `u8::from`, but as a `const fn`.
+            pub const fn raw(&self) -> u8 {
+                self.0
+            }
+        }
+
+        impl PartialEq<u8> for $REG {
+            fn eq(&self, other: &u8) -> bool {
+                self.0 == *other
+            }
+        }
+
         impl Default for $REG {
             fn default() -> Self {
                 Self::DEFAULT
@@ -40,6 +55,7 @@ macro_rules! impl_boilerplate_for {
 
 /// # Clock Delay Ctrl core register
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClockDelayCtrl(u8);
 impl_boilerplate_for!(ClockDelayCtrl);
 
@@ -206,6 +222,32 @@ impl ClockDelayCtrl {
         self.0 &= !Self::MMEN_MASK;
         self
     }
+    /// ## Check a job midstate count against the Multi Midstate mode.
+    ///
+    /// This returns `true` if jobs with `midstate_count` midstates are compatible
+    /// with the Multi Midstate mode (AsicBoost) state :
+    /// - disabled : exactly 1 midstate.
+    /// - enabled : 2 to 4 midstates.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::core_register::ClockDelayCtrl;
+    ///
+    /// let cdc: ClockDelayCtrl = ClockDelayCtrl::DEFAULT;
+    /// assert!(cdc.midstate_count_ok(1));
+    /// assert!(!cdc.midstate_count_ok(4));
+    /// let cdc: ClockDelayCtrl = cdc.enable_multi_midstate();
+    /// assert!(!cdc.midstate_count_ok(1));
+    /// assert!(cdc.midstate_count_ok(4));
+    /// ```
+    pub const fn midstate_count_ok(&self, midstate_count: u8) -> bool {
+        if self.multi_midstate_enabled() {
+            midstate_count >= 2 && midstate_count <= 4
+        } else {
+            midstate_count == 1
+        }
+    }
 
     /// ## Get the Sweep Frequency Mode state.
     ///
@@ -243,6 +285,7 @@ impl ClockDelayCtrl {
 impl ::core::fmt::Display for ClockDelayCtrl {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         f.debug_struct("ClockDelayCtrl")
+            .field("raw", &format_args!("{:#04x}", self.0))
             .field("ccdly", &self.ccdly())
             .field("pwth", &self.pwth())
             .field("hash_clock_enabled", &self.hash_clock_enabled())
@@ -260,7 +303,8 @@ impl defmt::Format for ClockDelayCtrl {
     fn format(&self, fmt: defmt::Formatter) {
         defmt::write!(
             fmt,
-            "ClockDelayCtrl {{ ccdly: {}, pwth: {}, hash_clock_enabled: {}, multi_midstate_enabled: {}, sweep_frequency_mode_enabled: {} }}",
+            "ClockDelayCtrl {{ raw: {=u8:#04x}, ccdly: {}, pwth: {}, hash_clock_enabled: {}, multi_midstate_enabled: {}, sweep_frequency_mode_enabled: {} }}",
+            self.0,
             self.ccdly(),
             self.pwth(),
             self.hash_clock_enabled(),
@@ -272,6 +316,7 @@ impl defmt::Format for ClockDelayCtrl {
 
 /// # Process Monitor Ctrl core register
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProcessMonitorCtrl(u8);
 impl_boilerplate_for!(ProcessMonitorCtrl);
 
@@ -348,6 +393,7 @@ impl ProcessMonitorCtrl {
 impl ::core::fmt::Display for ProcessMonitorCtrl {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         f.debug_struct("ProcessMonitorCtrl")
+            .field("raw", &format_args!("{:#04x}", self.0))
             .field("started", &self.started())
             .field("pm_sel", &self.pm_sel())
             .finish()
@@ -359,7 +405,8 @@ impl defmt::Format for ProcessMonitorCtrl {
     fn format(&self, fmt: defmt::Formatter) {
         defmt::write!(
             fmt,
-            "ProcessMonitorCtrl {{ started: {}, pm_sel: {} }}",
+            "ProcessMonitorCtrl {{ raw: {=u8:#04x}, started: {}, pm_sel: {} }}",
+            self.0,
             self.started(),
             self.pm_sel()
         );
@@ -368,6 +415,7 @@ impl defmt::Format for ProcessMonitorCtrl {
 
 /// # Process Monitor Data core register
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProcessMonitorData(u8);
 impl_boilerplate_for!(ProcessMonitorData);
 
@@ -425,6 +473,7 @@ impl ProcessMonitorData {
 impl ::core::fmt::Display for ProcessMonitorData {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         f.debug_struct("ProcessMonitorData")
+            .field("raw", &format_args!("{:#04x}", self.0))
             .field("data", &self.data())
             .finish()
     }
@@ -433,12 +482,58 @@ impl ::core::fmt::Display for ProcessMonitorData {
 #[cfg(feature = "defmt")]
 impl defmt::Format for ProcessMonitorData {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "ProcessMonitorData {{ data: {} }}", self.data());
+        defmt::write!(
+            fmt,
+            "ProcessMonitorData {{ raw: {=u8:#04x}, data: {} }}",
+            self.0,
+            self.data()
+        );
+    }
+}
+
+/// # Process Monitor Result
+///
+/// A `ProcessMonitorData` reading labeled with the `ProcessMonitorSelect` the measure was
+/// started on with `ProcessMonitorCtrl::start`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ProcessMonitorResult {
+    /// The delay chain the measure was started on.
+    pub select: ProcessMonitorSelect,
+    /// The measured data.
+    pub data: u8,
+}
+
+impl ProcessMonitorResult {
+    /// ## Label a Process Monitor reading.
+    ///
+    /// The `select` is taken from `ctrl.pm_sel()`, `ctrl` being the value written
+    /// to start the measure.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::core_register::{
+    ///     ProcessMonitorCtrl, ProcessMonitorData, ProcessMonitorResult,
+    /// };
+    /// use bm1397_protocol::specifier::ProcessMonitorSelect;
+    ///
+    /// let ctrl = ProcessMonitorCtrl::DEFAULT.start(ProcessMonitorSelect::SVTDelayChain);
+    /// let res = ProcessMonitorResult::new(ctrl, ProcessMonitorData::from(0x5A));
+    /// assert_eq!(res.select, ProcessMonitorSelect::SVTDelayChain);
+    /// assert_eq!(res.data, 0x5A);
+    /// ```
+    pub fn new(ctrl: ProcessMonitorCtrl, data: ProcessMonitorData) -> Self {
+        Self {
+            select: ctrl.pm_sel(),
+            data: data.data(),
+        }
     }
 }
 
 /// # Core Error core register
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CoreError(u8);
 impl_boilerplate_for!(CoreError);
 
@@ -500,6 +595,9 @@ impl CoreError {
     ///
     /// This returns an `u8` with the Command Error Count.
     ///
+    /// The 4-bits counter saturate at `15` : this value means 15 errors *or more*,
+    /// see `is_saturated`.
+    ///
     /// ### Example
     ///
     /// ```
@@ -508,14 +606,36 @@ impl CoreError {
     /// let ce: CoreError = CoreError::DEFAULT;
     /// assert_eq!(ce.cmd_err_cnt(), 0x00);
     /// ```
-    pub fn cmd_err_cnt(&self) -> u8 {
+    pub const fn cmd_err_cnt(&self) -> u8 {
         (self.0 & Self::CMD_ERR_CNT_MASK) >> Self::CMD_ERR_CNT_OFFSET
     }
+
+    /// ## Check if the Command Error Count is saturated.
+    ///
+    /// This returns `true` if CMD_ERR_CNT reached its maximum (`15`), so the actual
+    /// number of command errors is unknown, at least 15. When aggregating the counters
+    /// of many cores, a saturated one is a lower bound and the core need a closer look.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::core_register::CoreError;
+    ///
+    /// assert!(!CoreError::DEFAULT.is_saturated());
+    /// assert!(!CoreError::from(0x0E).is_saturated());
+    /// let ce = CoreError::from(0x0F);
+    /// assert_eq!(ce.cmd_err_cnt(), 15);
+    /// assert!(ce.is_saturated());
+    /// ```
+    pub const fn is_saturated(&self) -> bool {
+        self.0 & Self::CMD_ERR_CNT_MASK == Self::CMD_ERR_CNT_MASK
+    }
 }
 
 impl ::core::fmt::Display for CoreError {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         f.debug_struct("CoreError")
+            .field("raw", &format_args!("{:#04x}", self.0))
             .field("ini_nonce_err", &self.ini_nonce_err())
             .field("cmd_err_cnt", &self.cmd_err_cnt())
             .finish()
@@ -527,7 +647,8 @@ impl defmt::Format for CoreError {
     fn format(&self, fmt: defmt::Formatter) {
         defmt::write!(
             fmt,
-            "CoreError {{ ini_nonce_err: {}, cmd_err_cnt: {} }}",
+            "CoreError {{ raw: {=u8:#04x}, ini_nonce_err: {}, cmd_err_cnt: {} }}",
+            self.0,
             self.ini_nonce_err(),
             self.cmd_err_cnt()
         );
@@ -536,6 +657,7 @@ impl defmt::Format for CoreError {
 
 /// # Core Enable core register
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CoreEnable(u8);
 impl_boilerplate_for!(CoreEnable);
 
@@ -576,19 +698,22 @@ impl CoreEnable {
 
 impl ::core::fmt::Display for CoreEnable {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("CoreEnable").finish()
+        f.debug_struct("CoreEnable")
+            .field("raw", &format_args!("{:#04x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for CoreEnable {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "CoreEnable {{ }}",);
+        defmt::write!(fmt, "CoreEnable {{ raw: {=u8:#04x} }}", self.0);
     }
 }
 
 /// # Hash Clock Ctrl core register
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HashClockCtrl(u8);
 impl_boilerplate_for!(HashClockCtrl);
 
@@ -629,19 +754,22 @@ impl HashClockCtrl {
 
 impl ::core::fmt::Display for HashClockCtrl {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("HashClockCtrl").finish()
+        f.debug_struct("HashClockCtrl")
+            .field("raw", &format_args!("{:#04x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for HashClockCtrl {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "HashClockCtrl {{ }}",);
+        defmt::write!(fmt, "HashClockCtrl {{ raw: {=u8:#04x} }}", self.0);
     }
 }
 
 /// # Hash Clock Counter core register
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HashClockCounter(u8);
 impl_boilerplate_for!(HashClockCounter);
 
@@ -682,19 +810,22 @@ impl HashClockCounter {
 
 impl ::core::fmt::Display for HashClockCounter {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("HashClockCounter").finish()
+        f.debug_struct("HashClockCounter")
+            .field("raw", &format_args!("{:#04x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for HashClockCounter {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "HashClockCounter {{ }}",);
+        defmt::write!(fmt, "HashClockCounter {{ raw: {=u8:#04x} }}", self.0);
     }
 }
 
 /// # Sweep Clock Ctrl core register
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SweepClockCtrl(u8);
 impl_boilerplate_for!(SweepClockCtrl);
 
@@ -735,22 +866,122 @@ impl SweepClockCtrl {
     pub const SWPF_MODE_MASK: u8 = 0b1 << Self::SWPF_MODE_OFFSET;
     /// ## Bit mask for the `CLK_SEL` field.
     pub const CLK_SEL_MASK: u8 = 0b1111 << Self::CLK_SEL_OFFSET;
+
+    /// ## Get the Sweep Frequency Mode state.
+    ///
+    /// This returns an `bool` with the Sweep Frequency Mode state.
+    ///
+    /// It must agree with `ClockDelayCtrl::sweep_frequency_mode_enabled`,
+    /// see `Command::set_sweep_frequency_mode`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::core_register::SweepClockCtrl;
+    ///
+    /// let scc: SweepClockCtrl = SweepClockCtrl::DEFAULT;
+    /// assert!(!scc.sweep_frequency_mode_enabled());
+    /// let scc: SweepClockCtrl = scc.enable_sweep_frequency_mode();
+    /// assert!(scc.sweep_frequency_mode_enabled());
+    /// let scc: SweepClockCtrl = scc.disable_sweep_frequency_mode();
+    /// assert!(!scc.sweep_frequency_mode_enabled());
+    /// ```
+    pub const fn sweep_frequency_mode_enabled(&self) -> bool {
+        self.0 & Self::SWPF_MODE_MASK == Self::SWPF_MODE_MASK
+    }
+    /// ## Enable the Sweep Frequency Mode.
+    #[must_use = "enable_sweep_frequency_mode returns a modified SweepClockCtrl"]
+    pub const fn enable_sweep_frequency_mode(mut self) -> Self {
+        self.0 |= Self::SWPF_MODE_MASK;
+        self
+    }
+    /// ## Disable the Sweep Frequency Mode.
+    #[must_use = "disable_sweep_frequency_mode returns a modified SweepClockCtrl"]
+    pub const fn disable_sweep_frequency_mode(mut self) -> Self {
+        self.0 &= !Self::SWPF_MODE_MASK;
+        self
+    }
+
+    /// ## Get the Clock Select.
+    ///
+    /// This returns an `u8` with the index of the ordered clock used by the sweep.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::core_register::SweepClockCtrl;
+    ///
+    /// let scc: SweepClockCtrl = SweepClockCtrl::DEFAULT;
+    /// assert_eq!(scc.clk_sel(), 0);
+    /// let scc: SweepClockCtrl = scc.enable_sweep_frequency_mode().set_clk_sel(5);
+    /// assert_eq!(scc.clk_sel(), 5);
+    /// assert!(scc.sweep_frequency_mode_enabled());
+    /// // out of range bits are masked
+    /// let scc: SweepClockCtrl = scc.set_clk_sel(0x1F);
+    /// assert_eq!(scc.clk_sel(), 0x0F);
+    /// assert!(scc.sweep_frequency_mode_enabled());
+    /// ```
+    pub const fn clk_sel(&self) -> u8 {
+        (self.0 & Self::CLK_SEL_MASK) >> Self::CLK_SEL_OFFSET
+    }
+    /// ## Set the Clock Select.
+    #[must_use = "set_clk_sel returns a modified SweepClockCtrl"]
+    pub const fn set_clk_sel(mut self, clk_sel: u8) -> Self {
+        self.0 &= !Self::CLK_SEL_MASK;
+        self.0 |= (clk_sel << Self::CLK_SEL_OFFSET) & Self::CLK_SEL_MASK;
+        self
+    }
 }
 
 impl ::core::fmt::Display for SweepClockCtrl {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("SweepClockCtrl").finish()
+        f.debug_struct("SweepClockCtrl")
+            .field("raw", &format_args!("{:#04x}", self.0))
+            .field(
+                "sweep_frequency_mode_enabled",
+                &self.sweep_frequency_mode_enabled(),
+            )
+            .field("clk_sel", &self.clk_sel())
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for SweepClockCtrl {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "SweepClockCtrl {{ }}",);
+        defmt::write!(
+            fmt,
+            "SweepClockCtrl {{ raw: {=u8:#04x}, sweep_frequency_mode_enabled: {}, clk_sel: {} }}",
+            self.0,
+            self.sweep_frequency_mode_enabled(),
+            self.clk_sel(),
+        );
     }
 }
 
-#[derive(Debug, PartialEq)]
+/// # Core Register IDs
+///
+/// The id of every core register.
+///
+/// ### Example
+///
+/// ```
+/// use bm1397_protocol::core_register::{CoreError, CORE_REGISTER_IDS};
+///
+/// assert!(CORE_REGISTER_IDS.contains(&CoreError::ID));
+/// ```
+pub const CORE_REGISTER_IDS: &[u8] = &[
+    ClockDelayCtrl::ID,
+    ProcessMonitorCtrl::ID,
+    ProcessMonitorData::ID,
+    CoreError::ID,
+    CoreEnable::ID,
+    HashClockCtrl::ID,
+    HashClockCounter::ID,
+    SweepClockCtrl::ID,
+];
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub enum CoreRegisters {
     ClockDelayCtrl(ClockDelayCtrl),
     ProcessMonitorCtrl(ProcessMonitorCtrl),
@@ -761,3 +992,61 @@ pub enum CoreRegisters {
     HashClockCounter(HashClockCounter),
     SweepClockCtrl(SweepClockCtrl),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::register::CoreRegisterValue;
+
+    /// Test that every core register implement the common set of derives.
+    #[test]
+    fn common_derives() {
+        fn check_derives<T: core::fmt::Debug + Copy + Clone + Eq + PartialEq + core::hash::Hash>() {
+        }
+        check_derives::<ClockDelayCtrl>();
+        check_derives::<ProcessMonitorCtrl>();
+        check_derives::<ProcessMonitorData>();
+        check_derives::<CoreError>();
+        check_derives::<CoreEnable>();
+        check_derives::<HashClockCtrl>();
+        check_derives::<HashClockCounter>();
+        check_derives::<SweepClockCtrl>();
+        check_derives::<CoreRegisters>();
+    }
+
+    /// Test the core register ids table against each core register `ID`.
+    #[test]
+    fn core_register_ids() {
+        macro_rules! check_id {
+            ($REG:ident) => {
+                assert!(CORE_REGISTER_IDS.contains(&$REG::ID));
+                assert_eq!($REG::DEFAULT.raw(), $REG::RESET);
+                assert_eq!($REG::DEFAULT, $REG::RESET);
+                assert_eq!(
+                    CoreRegisterValue::DEFAULT.core_reg($REG::ID),
+                    Ok(CoreRegisters::$REG($REG::DEFAULT))
+                );
+            };
+        }
+        check_id!(ClockDelayCtrl);
+        check_id!(ProcessMonitorCtrl);
+        check_id!(ProcessMonitorData);
+        check_id!(CoreError);
+        check_id!(CoreEnable);
+        check_id!(HashClockCtrl);
+        check_id!(HashClockCounter);
+        check_id!(SweepClockCtrl);
+        assert_eq!(CORE_REGISTER_IDS.len(), 8);
+    }
+
+    /// Test that the core register ids are all distinct.
+    #[test]
+    fn core_register_ids_unique() {
+        for (i, id) in CORE_REGISTER_IDS.iter().enumerate() {
+            assert!(
+                !CORE_REGISTER_IDS[i + 1..].contains(id),
+                "duplicate core register id {id:#04x}"
+            );
+        }
+    }
+}
@@ -1,10 +1,24 @@
 #![no_std]
 //! BM1397 protocol driver.
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// Log a trace message with the `log` crate, only if the `log` feature is enabled.
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        #[cfg(feature = "log")]
+        log::trace!($($arg)*);
+    };
+}
+
 mod crc;
 
+pub mod chip_state;
 pub mod command;
 pub mod core_register;
+#[cfg(feature = "midstate")]
+pub mod midstate;
 pub mod register;
 pub mod response;
 pub mod specifier;
@@ -28,10 +42,61 @@ pub mod specifier;
 // pub use response::{JobResponse, RegisterResponse, Response, ResponseType};
 // pub use specifier::{BaudrateClockSelect, ClockSelect, ProcessMonitorSelect};
 
+/// Number of cores in a BM1397.
+///
+/// See [BM1397 documentation](https://github.com/skot/BM1397/blob/master/registers.md).
+pub const CORE_COUNT: usize = 168;
+
+/// Number of small cores in each core of a BM1397.
+///
+/// See [BM1397 documentation](https://github.com/skot/BM1397/blob/master/registers.md).
+pub const SMALL_CORES_PER_CORE: usize = 4;
+
+/// Number of small cores in a BM1397.
+pub const SMALL_CORE_COUNT: usize = CORE_COUNT * SMALL_CORES_PER_CORE;
+
+/// Bit offset of the Core ID in a returned nonce.
+///
+/// Each core hardcode the 8 most significant bits of the nonces it search with its own Core ID,
+/// as decoded by `Response::nonce2core_id`.
+///
+/// See [BM1397 documentation](https://github.com/skot/BM1397/blob/master/registers.md).
+pub const NONCE_CORE_ID_OFFSET: u8 = 24;
+
+/// Bit mask of the Core ID in a returned nonce.
+pub const NONCE_CORE_ID_MASK: u32 = 0xff << NONCE_CORE_ID_OFFSET;
+
+/// Bit offset of the Chip Address in a returned nonce.
+///
+/// Each chip hardcode `Nonce[23:16]` with its own `ChipAddress` to split the nonce space
+/// between all the chips of a chain, as decoded by `Response::nonce2chip_addr`.
+///
+/// See [BM1397 documentation](https://github.com/skot/BM1397/blob/master/registers.md).
+pub const NONCE_CHIP_ADDR_OFFSET: u8 = 16;
+
+/// Bit mask of the Chip Address in a returned nonce.
+pub const NONCE_CHIP_ADDR_MASK: u32 = 0xff << NONCE_CHIP_ADDR_OFFSET;
+
 #[derive(Debug, PartialEq)]
 pub enum Error {
     InvalidPreamble,
-    InvalidCrc,
+    /// The CRC5 of a short frame (expected computed, found received) is not valid.
+    InvalidCrc5 {
+        expected: u8,
+        found: u8,
+    },
+    /// The CRC16 of a job frame (expected computed, found received) is not valid.
+    InvalidCrc16 {
+        expected: u16,
+        found: u16,
+    },
+    /// The buffer length (given) does not match the expected frame length.
+    InvalidLength(usize),
+    /// The number of midstates (given) of a job is not 1, 2, 3 or 4,
+    /// or more than 7 for a raw job.
+    InvalidMidstateCount(usize),
+    /// The command byte (given) does not match a known command.
+    UnknownCommand(u8),
     UnknownRegister(u8),
     UnknownCoreRegister(u8),
 }
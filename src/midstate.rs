@@ -0,0 +1,214 @@
+//! BM1397 Midstates.
+//!
+//! The BM1397 does not receive the full block header : the host send the SHA-256 state
+//! after the first 64 bytes of the header (the midstate), followed by the last 12 bytes
+//! (`merkle_root` tail, `n_time` and `n_bits`) in the job command.
+
+use sha2::digest::generic_array::GenericArray;
+use sha2::{Digest, Sha256};
+
+use crate::command::Midstate;
+
+/// SHA-256 initial hash value.
+const SHA256_H0: [u32; 8] = [
+    0x6a09_e667,
+    0xbb67_ae85,
+    0x3c6e_f372,
+    0xa54f_f53a,
+    0x510e_527f,
+    0x9b05_688c,
+    0x1f83_d9ab,
+    0x5be0_cd19,
+];
+
+/// # Midstate
+///
+/// Compute the midstate of a block header, in the byte order expected by the chip in
+/// `Command::job_1_midstate` and `Command::job_4_midstate` : the SHA-256 state words
+/// after the first 64 bytes of `header`, in reverse order, each in little-endian.
+///
+/// The `header` is the 80 bytes serialized block header, as it is hashed.
+///
+/// ## Example
+///
+/// ```
+/// use bm1397_protocol::midstate::midstate;
+///
+/// let header = [0u8; 80];
+/// let ms = midstate(&header);
+/// assert_eq!(ms[..4], [0xD8, 0xA9, 0x37, 0x18]);
+/// ```
+pub fn midstate(header: &[u8; 80]) -> Midstate {
+    let mut state = SHA256_H0;
+    sha2::compress256(&mut state, &[*GenericArray::from_slice(&header[..64])]);
+    let mut ms: Midstate = [0; 32];
+    for (i, word) in state.iter().rev().enumerate() {
+        ms[i * 4..i * 4 + 4].clone_from_slice(&word.to_le_bytes());
+    }
+    ms
+}
+
+/// # Increment Version
+///
+/// Increment the bits of `version` selected by `version_mask`, the carry only propagating
+/// within the mask. Bits outside the mask are left untouched and the rolled bits wrap around.
+///
+/// ## Example
+///
+/// ```
+/// use bm1397_protocol::midstate::increment_version;
+///
+/// assert_eq!(increment_version(0x2000_0000, 0x1fff_e000), 0x2000_2000);
+/// assert_eq!(increment_version(0x3fff_e000, 0x1fff_e000), 0x2000_0000);
+/// assert_eq!(increment_version(0x2000_0000, 0), 0x2000_0000);
+/// ```
+pub const fn increment_version(version: u32, version_mask: u32) -> u32 {
+    ((version | !version_mask).wrapping_add(1) & version_mask) | (version & !version_mask)
+}
+
+/// # Midstates for Version Rolling
+///
+/// Compute the 4 midstates to send in `Command::job_4_midstate` when the Multi Midstate
+/// mode (AsicBoost) is enabled with `ClockDelayCtrl::enable_multi_midstate`.
+///
+/// The 4 midstates only differ by the `version` of the block header (the first 4 bytes,
+/// little-endian) : midstate `0` use the `header` version as is, and each following
+/// midstate use the previous version incremented within `version_mask`
+/// (see [`increment_version`]). The `midstate_id` of a returned nonce is the index of the
+/// midstate that produced it, so the version to submit is the one used for this index.
+///
+/// With a `version_mask` of `0`, the 4 midstates are identical.
+///
+/// ## Example
+///
+/// ```
+/// use bm1397_protocol::midstate::{midstate, midstates_for_version_rolling};
+///
+/// let header = [0u8; 80];
+/// let midstates = midstates_for_version_rolling(&header, 0x1fff_e000);
+/// assert_eq!(midstates[0], midstate(&header));
+/// assert_ne!(midstates[1], midstates[0]);
+///
+/// let midstates = midstates_for_version_rolling(&header, 0);
+/// assert!(midstates.iter().all(|ms| *ms == midstate(&header)));
+/// ```
+pub fn midstates_for_version_rolling(header: &[u8; 80], version_mask: u32) -> [Midstate; 4] {
+    let mut header = *header;
+    let mut version = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+    let mut midstates: [Midstate; 4] = [[0; 32]; 4];
+    for ms in midstates.iter_mut() {
+        header[0..4].clone_from_slice(&version.to_le_bytes());
+        *ms = midstate(&header);
+        version = increment_version(version, version_mask);
+    }
+    midstates
+}
+
+/// # Nonce Difficulty
+///
+/// Compute the difficulty of the share found with `nonce` : the block header is double
+/// SHA-256 hashed with `nonce` at bytes `76..80` (little-endian), and the difficulty is
+/// the difficulty 1 target (`0xffff << 208`) divided by the hash (as a 256 bits
+/// little-endian number).
+///
+/// The result is truncated to an integer and saturate to `u64::MAX` for very low hashes
+/// (including a zero hash).
+///
+/// ## Example
+///
+/// ```
+/// use bm1397_protocol::midstate::nonce_difficulty;
+///
+/// let header = [0u8; 80];
+/// assert_eq!(nonce_difficulty(0, &header), 0);
+/// ```
+pub fn nonce_difficulty(nonce: u32, header: &[u8; 80]) -> u64 {
+    let mut header = *header;
+    header[76..80].clone_from_slice(&nonce.to_le_bytes());
+    let hash = Sha256::digest(Sha256::digest(header));
+    // hash as a 256 bits little-endian number, its most significant 64 bits and position
+    let Some(msb) = hash.iter().rposition(|b| *b != 0) else {
+        return u64::MAX;
+    };
+    let bit_len = msb * 8 + 8 - hash[msb].leading_zeros() as usize;
+    let shift = bit_len.saturating_sub(64);
+    let mut top: u64 = 0;
+    for bit in (shift..bit_len).rev() {
+        top = (top << 1) | ((hash[bit / 8] >> (bit % 8)) & 1) as u64;
+    }
+    // difficulty ~= (0xffff << 208) / (top << shift) = (0xffff << (208 - shift)) / top
+    let num_shift = 208 - shift;
+    if num_shift >= 112 {
+        return u64::MAX;
+    }
+    u64::try_from((0xffff_u128 << num_shift) / top as u128).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Genesis block header.
+    const GENESIS: [u8; 80] = [
+        0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3B, 0xA3, 0xED, 0xFD, 0x7A, 0x7B, 0x12, 0xB2, 0x7A,
+        0xC7, 0x2C, 0x3E, 0x67, 0x76, 0x8F, 0x61, 0x7F, 0xC8, 0x1B, 0xC3, 0x88, 0x8A, 0x51, 0x32,
+        0x3A, 0x9F, 0xB8, 0xAA, 0x4B, 0x1E, 0x5E, 0x4A, 0x29, 0xAB, 0x5F, 0x49, 0xFF, 0xFF, 0x00,
+        0x1D, 0x1D, 0xAC, 0x2B, 0x7C,
+    ];
+
+    /// Test the midstate of the genesis block header.
+    #[test]
+    fn midstate_genesis() {
+        assert_eq!(
+            midstate(&GENESIS),
+            [
+                0x1B, 0xF9, 0x19, 0x47, 0x36, 0x87, 0xB1, 0x96, 0xC8, 0x03, 0x01, 0x4F, 0xE9, 0xD8,
+                0xC8, 0xC3, 0xA8, 0xCA, 0x59, 0x1E, 0x7D, 0xAC, 0xCC, 0x90, 0xF0, 0xBF, 0x58, 0x63,
+                0x33, 0x9A, 0x90, 0xBC
+            ]
+        );
+    }
+
+    /// Test the difficulty of the genesis block nonce.
+    #[test]
+    fn nonce_difficulty_genesis() {
+        assert_eq!(nonce_difficulty(0x7C2B_AC1D, &GENESIS), 2536);
+        assert_eq!(nonce_difficulty(0, &GENESIS), 0);
+    }
+
+    /// Test the 4 midstates of the genesis block header with the BIP320 version mask.
+    #[test]
+    fn midstates_for_version_rolling_genesis() {
+        assert_eq!(
+            midstates_for_version_rolling(&GENESIS, 0x1fff_e000),
+            [
+                // version 0x0000_0001
+                [
+                    0x1B, 0xF9, 0x19, 0x47, 0x36, 0x87, 0xB1, 0x96, 0xC8, 0x03, 0x01, 0x4F, 0xE9,
+                    0xD8, 0xC8, 0xC3, 0xA8, 0xCA, 0x59, 0x1E, 0x7D, 0xAC, 0xCC, 0x90, 0xF0, 0xBF,
+                    0x58, 0x63, 0x33, 0x9A, 0x90, 0xBC
+                ],
+                // version 0x0000_2001
+                [
+                    0x78, 0xEE, 0x7C, 0x03, 0x8D, 0x60, 0xA4, 0x46, 0x94, 0x82, 0x57, 0x1C, 0xEA,
+                    0x13, 0xAC, 0x54, 0xD3, 0x3E, 0x8C, 0x7F, 0x59, 0xC1, 0xE6, 0xA5, 0xCF, 0x36,
+                    0xD7, 0x85, 0x39, 0xD0, 0x3D, 0x2A
+                ],
+                // version 0x0000_4001
+                [
+                    0x8D, 0x55, 0xA0, 0x7B, 0x3C, 0x00, 0x40, 0x87, 0x1B, 0x62, 0x27, 0xC5, 0x53,
+                    0xEC, 0x32, 0xCE, 0x5C, 0x79, 0xC9, 0xE3, 0x15, 0x2A, 0xB1, 0x9A, 0xE0, 0x1C,
+                    0x0C, 0x12, 0xAA, 0x2A, 0x43, 0x8A
+                ],
+                // version 0x0000_6001
+                [
+                    0x58, 0x3F, 0x49, 0x02, 0x0F, 0xE2, 0xC9, 0x45, 0xEE, 0x8E, 0xF5, 0x4E, 0x35,
+                    0x38, 0xF2, 0x70, 0x87, 0x8E, 0xDB, 0x47, 0xFF, 0x0B, 0x91, 0x95, 0xE6, 0x56,
+                    0xC2, 0x97, 0xD2, 0xB4, 0x1D, 0x22
+                ],
+            ]
+        );
+    }
+}
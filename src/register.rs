@@ -1,7 +1,7 @@
 //! BM1397 Registers.
 
 use crate::core_register::*;
-use crate::specifier::{BaudrateClockSelect, ClockSelect};
+use crate::specifier::{BaudrateClockSelect, ChipVariant, ClockSelect};
 use crate::Error;
 use fugit::HertzU32;
 
@@ -10,6 +10,135 @@ pub trait Register {
     fn val(&self) -> u32;
 }
 
+/// # Const Register
+///
+/// A `Register` whose address is known at compile time.
+///
+/// This is a separate trait so `Register` can still be used as a trait object.
+///
+/// ## Example
+///
+/// ```
+/// use bm1397_protocol::register::{ChipAddress, ConstRegister, MiscControl, Register};
+///
+/// fn shadow_store<R: ConstRegister>(shadow: &mut [Option<u32>; 0x100], reg: R) {
+///     shadow[R::ADDR as usize] = Some(reg.val());
+/// }
+///
+/// let mut shadow = [None; 0x100];
+/// shadow_store(&mut shadow, MiscControl::DEFAULT);
+/// assert_eq!(shadow[MiscControl::ADDR as usize], Some(MiscControl::RESET));
+/// assert_eq!(shadow[ChipAddress::ADDR as usize], None);
+/// ```
+pub trait ConstRegister: Register {
+    const ADDR: u8;
+}
+
+/// # Writable Register
+///
+/// A `Register` which can be written with `Command::write_reg`.
+///
+/// Status registers (`HashRate`, `ErrorFlag`, the counters, ...) are only updated by the chip,
+/// writing them is at best a no-op, so they do not implement this trait.
+/// `HashCountingNumber` implements it, as the reference init sequences write it.
+/// Registers mixing configuration and status fields (`ExternalTemperatureSensorRead`)
+/// implement it, writing their status fields has no documented effect.
+///
+/// ## Example
+///
+/// ```
+/// use bm1397_protocol::command::{Command, Destination};
+/// use bm1397_protocol::register::MiscControl;
+///
+/// let cmd = Command::write_reg(MiscControl::DEFAULT, Destination::All);
+/// assert_eq!(cmd[5], 0x18);
+/// ```
+///
+/// ```compile_fail
+/// use bm1397_protocol::command::{Command, Destination};
+/// use bm1397_protocol::register::ErrorFlag;
+///
+/// let cmd = Command::write_reg(ErrorFlag::DEFAULT, Destination::All);
+/// ```
+pub trait WritableRegister: Register {}
+
+macro_rules! impl_writable_for {
+    ($($REG:ident),+ $(,)?) => {
+        $(impl WritableRegister for $REG {})+
+
+        /// # Writable Addresses
+        ///
+        /// The address of every `WritableRegister`.
+        ///
+        /// ### Example
+        ///
+        /// ```
+        /// use bm1397_protocol::register::{ErrorFlag, MiscControl, WRITABLE_ADDRS};
+        ///
+        /// assert!(WRITABLE_ADDRS.contains(&MiscControl::ADDR));
+        /// assert!(!WRITABLE_ADDRS.contains(&ErrorFlag::ADDR));
+        /// ```
+        pub const WRITABLE_ADDRS: &[u8] = &[$($REG::ADDR),+];
+    };
+}
+
+/// # Is Writable
+///
+/// Check if the register at address `addr` is a `WritableRegister`.
+///
+/// This returns `false` if `addr` do not match a known register.
+///
+/// ### Example
+///
+/// ```
+/// use bm1397_protocol::register::{is_writable, ErrorFlag, MiscControl};
+///
+/// assert!(is_writable(MiscControl::ADDR));
+/// assert!(!is_writable(ErrorFlag::ADDR));
+/// assert!(!is_writable(0xF0));
+/// ```
+pub const fn is_writable(addr: u8) -> bool {
+    let mut i = 0;
+    while i < WRITABLE_ADDRS.len() {
+        if WRITABLE_ADDRS[i] == addr {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+impl_writable_for!(
+    ChipAddress,
+    PLL0Parameter,
+    ChipNonceOffset,
+    HashCountingNumber,
+    TicketMask,
+    MiscControl,
+    I2CControl,
+    OrderedClockEnable,
+    FastUARTConfiguration,
+    UARTRelay,
+    TicketMask2,
+    CoreRegisterControl,
+    ExternalTemperatureSensorRead,
+    AnalogMuxControl,
+    IoDriverStrenghtConfiguration,
+    TimeOut,
+    PLL1Parameter,
+    PLL2Parameter,
+    PLL3Parameter,
+    OrderedClockMonitor,
+    PLL0Divider,
+    PLL1Divider,
+    PLL2Divider,
+    PLL3Divider,
+    ClockOrderControl0,
+    ClockOrderControl1,
+    FrequencySweepControl1,
+    NonceReturnedTimeout,
+);
+
 macro_rules! impl_boilerplate_for {
     ($REG:ident) => {
         impl From<u32> for $REG {
@@ -24,6 +153,56 @@ macro_rules! impl_boilerplate_for {
             }
         }
 
+        impl $REG {
+            /// ## Get the raw value.
+            ///
+            /// This is the same as `u32::from`, but as a `const fn`.
+            pub const fn raw(&self) -> u32 {
+                self.0
+            }
+
+            /// ## Build from the wire bytes.
+            ///
+            /// The register value is sent on the UART in big-endian order, in both
+            /// `Command::write_reg` and the register responses.
+            pub const fn from_be_bytes(bytes: [u8; 4]) -> Self {
+                Self(u32::from_be_bytes(bytes))
+            }
+
+            /// ## Get the wire bytes.
+            ///
+            /// This returns the register value in big-endian order, as sent on the UART.
+            pub const fn to_be_bytes(&self) -> [u8; 4] {
+                self.0.to_be_bytes()
+            }
+
+            /// ## Check the reserved bits.
+            ///
+            /// This returns `true` if the bits in `RESERVED_MASK` still have their `RESET` value,
+            /// so the register can be written without disturbing them.
+            // some registers do not have any reserved bit
+            #[allow(clippy::bad_bit_mask)]
+            pub const fn reserved_ok(&self) -> bool {
+                self.0 & Self::RESERVED_MASK == Self::RESET & Self::RESERVED_MASK
+            }
+
+            /// ## Merge the fields of another value.
+            ///
+            /// This returns a copy of `self` where the bits in `mask` are taken from `other`,
+            /// typically the `*_MASK` of the fields to change, to update a freshly read
+            /// register without clobbering its other fields.
+            #[must_use = "with_field_from returns a modified register"]
+            pub const fn with_field_from(self, other: &Self, mask: u32) -> Self {
+                Self((self.0 & !mask) | (other.0 & mask))
+            }
+        }
+
+        impl PartialEq<u32> for $REG {
+            fn eq(&self, other: &u32) -> bool {
+                self.0 == *other
+            }
+        }
+
         impl Default for $REG {
             fn default() -> Self {
                 Self::DEFAULT
@@ -38,13 +217,41 @@ macro_rules! impl_boilerplate_for {
                 self.0
             }
         }
+
+        impl ConstRegister for $REG {
+            const ADDR: u8 = $REG::ADDR;
+        }
     };
 }
 
 /// # Chip Address register
 ///
 /// Used to identify chip.
+///
+/// As all the registers, its `Display` start with the raw value, to match a UART capture.
+///
+/// ## Example
+///
+/// ```
+/// use bm1397_protocol::register::ChipAddress;
+///
+/// assert_eq!(
+///     format!("{}", ChipAddress::DEFAULT),
+///     "ChipAddress { raw: 0x13971800, chip_id: 5015, core_num: 24, chip_addr: 0 }"
+/// );
+/// ```
+///
+/// As all the registers, it can be built from its wire bytes, in big-endian order.
+///
+/// ```
+/// use bm1397_protocol::register::ChipAddress;
+///
+/// let chip_addr = ChipAddress::from_be_bytes([0x13, 0x97, 0x18, 0x00]);
+/// assert_eq!(chip_addr, ChipAddress::DEFAULT);
+/// assert_eq!(chip_addr.to_be_bytes(), [0x13, 0x97, 0x18, 0x00]);
+/// ```
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChipAddress(u32);
 impl_boilerplate_for!(ChipAddress);
 
@@ -89,6 +296,8 @@ impl ChipAddress {
     pub const CORE_NUM_MASK: u32 = 0xff << Self::CORE_NUM_OFFSET;
     /// ## Bit mask for the `ADDR` field.
     pub const ADDR_MASK: u32 = 0xff << Self::ADDR_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !(Self::CHIP_ID_MASK | Self::CORE_NUM_MASK | Self::ADDR_MASK);
 
     /// ## Get the chip identifier.
     ///
@@ -105,6 +314,28 @@ impl ChipAddress {
         (self.0 >> Self::CHIP_ID_OFFSET) as u16
     }
 
+    /// ## Get the chip variant.
+    ///
+    /// This returns an `Option<ChipVariant>` with the variant matching the chip_id value,
+    /// or `None` for an unknown chip.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::ChipAddress;
+    /// use bm1397_protocol::specifier::ChipVariant;
+    ///
+    /// assert_eq!(ChipAddress::DEFAULT.variant(), Some(ChipVariant::BM1397));
+    /// assert_eq!(ChipAddress::from(0x1366_0000).variant(), Some(ChipVariant::BM1366));
+    /// assert_eq!(ChipAddress::from(0x1234_0000).variant(), None);
+    /// ```
+    pub const fn variant(&self) -> Option<ChipVariant> {
+        match ChipVariant::from_raw(self.chip_id()) {
+            Ok(variant) => Some(variant),
+            Err(_) => None,
+        }
+    }
+
     /// ## Get the number of internal cores.
     ///
     /// This returns an `u8` with the core_num value.
@@ -139,6 +370,7 @@ impl ChipAddress {
 impl ::core::fmt::Display for ChipAddress {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         f.debug_struct("ChipAddress")
+            .field("raw", &format_args!("{:#010x}", self.0))
             .field("chip_id", &self.chip_id())
             .field("core_num", &self.core_num())
             .field("chip_addr", &self.chip_addr())
@@ -151,7 +383,8 @@ impl defmt::Format for ChipAddress {
     fn format(&self, fmt: defmt::Formatter) {
         defmt::write!(
             fmt,
-            "ChipAddress {{ chip_id: {}, core_num: {}, chip_addr: {} }}",
+            "ChipAddress {{ raw: {=u32:#010x}, chip_id: {}, core_num: {}, chip_addr: {} }}",
+            self.0,
             self.chip_id(),
             self.core_num(),
             self.chip_addr(),
@@ -163,6 +396,7 @@ impl defmt::Format for ChipAddress {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HashRate(u32);
 impl_boilerplate_for!(HashRate);
 
@@ -203,18 +437,22 @@ impl HashRate {
     pub const LONG_MASK: u32 = 0b1 << Self::LONG_OFFSET;
     /// ## Bit mask for the `HASHRATE` field.
     pub const HASHRATE_MASK: u32 = 0x7fff_ffff << Self::HASHRATE_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !(Self::LONG_MASK | Self::HASHRATE_MASK);
 }
 
 impl ::core::fmt::Display for HashRate {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("HashRate").finish()
+        f.debug_struct("HashRate")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for HashRate {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "HashRate {{  }}",);
+        defmt::write!(fmt, "HashRate {{ raw: {=u32:#010x} }}", self.0);
     }
 }
 
@@ -222,6 +460,7 @@ impl defmt::Format for HashRate {
 ///
 /// Used to set PLL0 frequency.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PLL0Parameter(u32);
 impl_boilerplate_for!(PLL0Parameter);
 
@@ -278,6 +517,37 @@ impl PLL0Parameter {
     pub const POSTDIV1_MASK: u32 = 0x7 << Self::POSTDIV1_OFFSET;
     /// ## Bit mask for the `POSTDIV2` field.
     pub const POSTDIV2_MASK: u32 = 0x7 << Self::POSTDIV2_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !(Self::LOCKED_MASK
+        | Self::PLLEN_MASK
+        | Self::FBDIV_MASK
+        | Self::REFDIV_MASK
+        | Self::POSTDIV1_MASK
+        | Self::POSTDIV2_MASK);
+
+    /// ## Build an enabled PLL0 Parameter from its dividers.
+    ///
+    /// Same as chaining `set_fbdiv`, `set_refdiv`, `set_postdiv1` and `set_postdiv2`
+    /// on an enabled PLL0 : the dividers are masked, use the `try_set_*` methods
+    /// to check their range. A 0 divider is kept, `frequency` then returns 0 Hz.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL0Parameter;
+    /// use fugit::HertzU32;
+    ///
+    /// const PLL0: PLL0Parameter = PLL0Parameter::from_dividers(0x60, 1, 6, 1);
+    /// assert!(PLL0.enabled());
+    /// assert_eq!(PLL0.frequency(HertzU32::MHz(25)), HertzU32::MHz(400u32));
+    /// ```
+    pub const fn from_dividers(fbdiv: u16, refdiv: u8, postdiv1: u8, postdiv2: u8) -> Self {
+        Self(Self::PLLEN_MASK)
+            .set_fbdiv(fbdiv)
+            .set_refdiv(refdiv)
+            .set_postdiv1(postdiv1)
+            .set_postdiv2(postdiv2)
+    }
 
     /// ## Get the PLL0 locked state.
     ///
@@ -369,6 +639,27 @@ impl PLL0Parameter {
         self.0 |= ((fbdiv as u32) << Self::FBDIV_OFFSET) & Self::FBDIV_MASK;
         self
     }
+    /// ## Set the PLL0 FB Divider, checking its range.
+    ///
+    /// This returns an `Err(u16)` with the given value if it is greater than `0xFFF`,
+    /// instead of silently masking it like `set_fbdiv`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL0Parameter;
+    ///
+    /// let pll0 = PLL0Parameter::DEFAULT.try_set_fbdiv(0x0AAA);
+    /// assert_eq!(pll0.unwrap().fbdiv(), 0x0AAA);
+    /// let pll0 = PLL0Parameter::DEFAULT.try_set_fbdiv(0x1000);
+    /// assert_eq!(pll0, Err(0x1000));
+    /// ```
+    pub const fn try_set_fbdiv(self, fbdiv: u16) -> Result<Self, u16> {
+        if (fbdiv as u32) > (Self::FBDIV_MASK >> Self::FBDIV_OFFSET) {
+            return Err(fbdiv);
+        }
+        Ok(self.set_fbdiv(fbdiv))
+    }
 
     /// ## Get the PLL0 REF Divider.
     ///
@@ -396,6 +687,28 @@ impl PLL0Parameter {
         self.0 |= ((refdiv as u32) << Self::REFDIV_OFFSET) & Self::REFDIV_MASK;
         self
     }
+    /// ## Set the PLL0 REF Divider, checking its range.
+    ///
+    /// This returns an `Err(u8)` with the given value if it is 0 or greater than `0x3F`,
+    /// instead of silently masking it like `set_refdiv`. A null divider has no frequency.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL0Parameter;
+    ///
+    /// let pll0 = PLL0Parameter::DEFAULT.try_set_refdiv(0x2A);
+    /// assert_eq!(pll0.unwrap().refdiv(), 0x2A);
+    /// let pll0 = PLL0Parameter::DEFAULT.try_set_refdiv(0x40);
+    /// assert_eq!(pll0, Err(0x40));
+    /// assert_eq!(PLL0Parameter::DEFAULT.try_set_refdiv(0), Err(0));
+    /// ```
+    pub const fn try_set_refdiv(self, refdiv: u8) -> Result<Self, u8> {
+        if refdiv == 0 || (refdiv as u32) > (Self::REFDIV_MASK >> Self::REFDIV_OFFSET) {
+            return Err(refdiv);
+        }
+        Ok(self.set_refdiv(refdiv))
+    }
 
     /// ## Get the PLL0 POST Divider 1.
     ///
@@ -423,6 +736,28 @@ impl PLL0Parameter {
         self.0 |= ((postdiv1 as u32) << Self::POSTDIV1_OFFSET) & Self::POSTDIV1_MASK;
         self
     }
+    /// ## Set the PLL0 POST Divider 1, checking its range.
+    ///
+    /// This returns an `Err(u8)` with the given value if it is 0 or greater than `0x7`,
+    /// instead of silently masking it like `set_postdiv1`. A null divider has no frequency.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL0Parameter;
+    ///
+    /// let pll0 = PLL0Parameter::DEFAULT.try_set_postdiv1(0x07);
+    /// assert_eq!(pll0.unwrap().postdiv1(), 0x07);
+    /// let pll0 = PLL0Parameter::DEFAULT.try_set_postdiv1(0x08);
+    /// assert_eq!(pll0, Err(0x08));
+    /// assert_eq!(PLL0Parameter::DEFAULT.try_set_postdiv1(0), Err(0));
+    /// ```
+    pub const fn try_set_postdiv1(self, postdiv1: u8) -> Result<Self, u8> {
+        if postdiv1 == 0 || (postdiv1 as u32) > (Self::POSTDIV1_MASK >> Self::POSTDIV1_OFFSET) {
+            return Err(postdiv1);
+        }
+        Ok(self.set_postdiv1(postdiv1))
+    }
 
     /// ## Get the PLL0 POST Divider 2.
     ///
@@ -450,10 +785,55 @@ impl PLL0Parameter {
         self.0 |= ((postdiv2 as u32) << Self::POSTDIV2_OFFSET) & Self::POSTDIV2_MASK;
         self
     }
+    /// ## Set the PLL0 POST Divider 2, checking its range.
+    ///
+    /// This returns an `Err(u8)` with the given value if it is 0 or greater than `0x7`,
+    /// instead of silently masking it like `set_postdiv2`. A null divider has no frequency.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL0Parameter;
+    ///
+    /// let pll0 = PLL0Parameter::DEFAULT.try_set_postdiv2(0x07);
+    /// assert_eq!(pll0.unwrap().postdiv2(), 0x07);
+    /// let pll0 = PLL0Parameter::DEFAULT.try_set_postdiv2(0x08);
+    /// assert_eq!(pll0, Err(0x08));
+    /// assert_eq!(PLL0Parameter::DEFAULT.try_set_postdiv2(0), Err(0));
+    /// ```
+    pub const fn try_set_postdiv2(self, postdiv2: u8) -> Result<Self, u8> {
+        if postdiv2 == 0 || (postdiv2 as u32) > (Self::POSTDIV2_MASK >> Self::POSTDIV2_OFFSET) {
+            return Err(postdiv2);
+        }
+        Ok(self.set_postdiv2(postdiv2))
+    }
+
+    /// ## Get the PLL0 divider ratio.
+    ///
+    /// This returns an `(u16, u32)` with the multiply factor `fbdiv` and the divide factor
+    /// `refdiv * postdiv1 * postdiv2`, so the PLL0 Frequency is `clki_freq * fbdiv / divider`.
+    /// A null divider means the PLL0 Frequency can not be computed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL0Parameter;
+    ///
+    /// assert_eq!(PLL0Parameter::from_dividers(0x60, 1, 6, 1).divider_ratio(), (0x60, 6));
+    /// assert_eq!(PLL0Parameter::from_dividers(0x60, 0, 6, 1).divider_ratio(), (0x60, 0));
+    /// ```
+    pub const fn divider_ratio(&self) -> (u16, u32) {
+        (
+            self.fbdiv(),
+            (self.refdiv() as u32) * (self.postdiv1() as u32) * (self.postdiv2() as u32),
+        )
+    }
 
     /// ## Get the PLL0 Frequency.
     ///
     /// This returns an `HertzU32` with the PLL0 Frequency according to the clki_freq parameter.
+    /// A 0 `REFDIV`, `POSTDIV1` or `POSTDIV2` divider gives 0 Hz, and the result saturates
+    /// at `u32::MAX` Hz.
     ///
     /// ### Example
     ///
@@ -463,18 +843,27 @@ impl PLL0Parameter {
     ///
     /// let clki_freq = HertzU32::MHz(25);
     /// assert_eq!(PLL0Parameter::DEFAULT.frequency(clki_freq), HertzU32::MHz(400u32));
+    /// let pll0 = PLL0Parameter::DEFAULT.set_postdiv2(0);
+    /// assert_eq!(pll0.frequency(clki_freq), HertzU32::from_raw(0));
     /// ```
     pub const fn frequency(&self, clki_freq: HertzU32) -> HertzU32 {
-        HertzU32::from_raw(
-            clki_freq.raw() * (self.fbdiv() as u32)
-                / ((self.refdiv() as u32) * (self.postdiv1() as u32) * (self.postdiv2() as u32)),
-        )
+        let div = (self.refdiv() as u64) * (self.postdiv1() as u64) * (self.postdiv2() as u64);
+        if div == 0 {
+            return HertzU32::from_raw(0);
+        }
+        let freq = (clki_freq.raw() as u64) * (self.fbdiv() as u64) / div;
+        if freq > u32::MAX as u64 {
+            HertzU32::from_raw(u32::MAX)
+        } else {
+            HertzU32::from_raw(freq as u32)
+        }
     }
 }
 
 impl ::core::fmt::Display for PLL0Parameter {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         f.debug_struct("PLL0Parameter")
+            .field("raw", &format_args!("{:#010x}", self.0))
             .field("locked", &self.locked())
             .field("enabled", &self.enabled())
             .field("fbdiv", &self.fbdiv())
@@ -490,7 +879,8 @@ impl defmt::Format for PLL0Parameter {
     fn format(&self, fmt: defmt::Formatter) {
         defmt::write!(
             fmt,
-            "PLL0Parameter {{ locked: {}, enabled: {}, fbdiv: {}, refdiv: {}, postdiv1: {}, postdiv2: {} }}",
+            "PLL0Parameter {{ raw: {=u32:#010x}, locked: {}, enabled: {}, fbdiv: {}, refdiv: {}, postdiv1: {}, postdiv2: {} }}",
+            self.0,
             self.locked(),
             self.enabled(),
             self.fbdiv(),
@@ -505,6 +895,7 @@ impl defmt::Format for PLL0Parameter {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChipNonceOffset(u32);
 impl_boilerplate_for!(ChipNonceOffset);
 
@@ -545,18 +936,22 @@ impl ChipNonceOffset {
     pub const CNOV_MASK: u32 = 0b1 << Self::CNOV_OFFSET;
     /// ## Bit mask for the `CNO` field.
     pub const CNO_MASK: u32 = 0b111 << Self::CNO_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !(Self::CNOV_MASK | Self::CNO_MASK);
 }
 
 impl ::core::fmt::Display for ChipNonceOffset {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("ChipNonceOffset").finish()
+        f.debug_struct("ChipNonceOffset")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for ChipNonceOffset {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "ChipNonceOffset {{  }}",);
+        defmt::write!(fmt, "ChipNonceOffset {{ raw: {=u32:#010x} }}", self.0);
     }
 }
 
@@ -564,6 +959,7 @@ impl defmt::Format for ChipNonceOffset {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HashCountingNumber(u32);
 impl_boilerplate_for!(HashCountingNumber);
 
@@ -600,18 +996,71 @@ impl HashCountingNumber {
 
     /// ## Bit mask for the `HCN` field.
     pub const HCN_MASK: u32 = 0xffff_ffff << Self::HCN_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !Self::HCN_MASK;
+
+    /// ## Get the HCN.
+    ///
+    /// This returns an `u32` with the raw Hash Counting Number.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::HashCountingNumber;
+    ///
+    /// assert_eq!(HashCountingNumber::DEFAULT.hcn(), 0);
+    /// assert_eq!(HashCountingNumber::from(0x1234_5678).hcn(), 0x1234_5678);
+    /// ```
+    pub const fn hcn(&self) -> u32 {
+        (self.0 & Self::HCN_MASK) >> Self::HCN_OFFSET
+    }
+}
+
+/// # Error rate
+///
+/// Compute the error rate, in errors per hash, from a number of errors `err` and a number
+/// of hashes `hashes` counted over the same interval.
+///
+/// Only uses a float division, which is available in `core` without `libm`.
+///
+/// Returns 0.0 if `hashes` is null.
+///
+/// ## Example
+///
+/// ```
+/// use bm1397_protocol::register::error_rate;
+///
+/// assert_eq!(error_rate(5, 1_000), 0.005);
+/// assert_eq!(error_rate(0, 1_000), 0.0);
+/// assert_eq!(error_rate(3, 0), 0.0);
+/// // hardware error percentage
+/// assert_eq!(error_rate(1, 400) * 100.0, 0.25);
+/// ```
+pub fn error_rate(err: u32, hashes: u32) -> f32 {
+    match hashes {
+        0 => 0.0,
+        h => err as f32 / h as f32,
+    }
 }
 
 impl ::core::fmt::Display for HashCountingNumber {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("HashCountingNumber").finish()
+        f.debug_struct("HashCountingNumber")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .field("hcn", &self.hcn())
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for HashCountingNumber {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "HashCountingNumber {{  }}",);
+        defmt::write!(
+            fmt,
+            "HashCountingNumber {{ raw: {=u32:#010x}, hcn: {} }}",
+            self.0,
+            self.hcn(),
+        );
     }
 }
 
@@ -619,6 +1068,7 @@ impl defmt::Format for HashCountingNumber {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TicketMask(u32);
 impl_boilerplate_for!(TicketMask);
 
@@ -667,6 +1117,9 @@ impl TicketMask {
     pub const TM1_MASK: u32 = 0xff << Self::TM1_OFFSET;
     /// ## Bit mask for the `TM0` field.
     pub const TM0_MASK: u32 = 0xff << Self::TM0_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 =
+        !(Self::TM3_MASK | Self::TM2_MASK | Self::TM1_MASK | Self::TM0_MASK);
 
     /// ## Create a new `TicketMask` from a difficulty.
     ///
@@ -686,14 +1139,16 @@ impl TicketMask {
 
 impl ::core::fmt::Display for TicketMask {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("TicketMask").finish()
+        f.debug_struct("TicketMask")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for TicketMask {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "TicketMask {{  }}",);
+        defmt::write!(fmt, "TicketMask {{ raw: {=u32:#010x} }}", self.0);
     }
 }
 
@@ -701,6 +1156,7 @@ impl defmt::Format for TicketMask {
 ///
 /// Used to control various settings.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MiscControl(u32);
 impl_boilerplate_for!(MiscControl);
 
@@ -786,9 +1242,34 @@ impl MiscControl {
     /// ## Bit mask for the `RET_WORK_ERR_FLAG` field.
     pub const RET_WORK_ERR_FLAG_MASK: u32 = 0b1 << Self::RET_WORK_ERR_FLAG_OFFSET;
     /// ## Bit mask for the `TFS` field.
-    pub const TFS_MASK: u32 = 0xb111 << Self::TFS_OFFSET;
+    pub const TFS_MASK: u32 = 0b111 << Self::TFS_OFFSET;
     /// ## Bit mask for the `HASHRATE_TWS` field.
-    pub const HASHRATE_TWS_MASK: u32 = 0xb11 << Self::HASHRATE_TWS_OFFSET;
+    pub const HASHRATE_TWS_MASK: u32 = 0b11 << Self::HASHRATE_TWS_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::MiscControl;
+    ///
+    /// assert_eq!(MiscControl::RESERVED_MASK, 0xF080_000C);
+    /// assert!(MiscControl::DEFAULT.reserved_ok());
+    /// assert!(!MiscControl::from(0x1000_3A01).reserved_ok());
+    /// ```
+    pub const RESERVED_MASK: u32 = !(Self::BT8D_8_5_MASK
+        | Self::CORE_SRST_MASK
+        | Self::SPAT_NOD_MASK
+        | Self::RVS_K0_MASK
+        | Self::DSCLK_SEL_MASK
+        | Self::TOP_CLK_SEL_MASK
+        | Self::BCK_SEL_MASK
+        | Self::RET_ERR_NONCE_MASK
+        | Self::RFS_MASK
+        | Self::INV_CLKO_MASK
+        | Self::BT8D_4_0_MASK
+        | Self::RET_WORK_ERR_FLAG_MASK
+        | Self::TFS_MASK
+        | Self::HASHRATE_TWS_MASK);
 
     /// ## Get the BT8D.
     ///
@@ -877,14 +1358,186 @@ impl MiscControl {
         }
         self
     }
+
+    /// ## Get the UART chain Baudrate.
+    ///
+    /// This returns an `u32` with the baudrate of the chain, according to the current
+    /// Baudrate Clock Select : `fbase / (8 * (BT8D + 1))` where `fbase` is
+    /// - `clki_freq` if `BCK_SEL` select `BaudrateClockSelect::Clki`,
+    /// - `pll3_freq / (PLL3_DIV4 + 1)` of `uart_conf` if `BCK_SEL` select
+    ///   `BaudrateClockSelect::Pll3`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::{FastUARTConfiguration, MiscControl, PLL3Parameter};
+    /// use bm1397_protocol::specifier::BaudrateClockSelect;
+    /// use fugit::HertzU32;
+    ///
+    /// let clki = HertzU32::MHz(25);
+    /// let pll3 = PLL3Parameter::DEFAULT.set_fbdiv(112).frequency(clki);
+    /// let uart_conf = FastUARTConfiguration::DEFAULT.set_pll3_div4(6);
+    ///
+    /// let misc = MiscControl::DEFAULT.set_bt8d(26);
+    /// assert_eq!(misc.chain_baudrate(&uart_conf, clki, pll3), 115_740);
+    /// let misc = misc.set_bclk_sel(BaudrateClockSelect::Pll3).set_bt8d(7);
+    /// assert_eq!(misc.chain_baudrate(&uart_conf, clki, pll3), 6_250_000);
+    /// ```
+    pub const fn chain_baudrate(
+        &self,
+        uart_conf: &FastUARTConfiguration,
+        clki_freq: HertzU32,
+        pll3_freq: HertzU32,
+    ) -> u32 {
+        let fbase = match self.bclk_sel() {
+            BaudrateClockSelect::Clki => clki_freq.raw(),
+            BaudrateClockSelect::Pll3 => pll3_freq.raw() / (uart_conf.pll3_div4() as u32 + 1),
+        };
+        fbase / (8 * (self.bt8d() as u32 + 1))
+    }
+
+    /// ## Get the RFS (Read Frequency Select).
+    ///
+    /// This returns an `bool` with the RFS state, `false` at reset.
+    ///
+    /// The BM1397 documentation does not describe its effect on the `HashRate`
+    /// frames cadence, nor its interaction with `HASHRATE_TWS`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::MiscControl;
+    ///
+    /// let misc: MiscControl = MiscControl::DEFAULT;
+    /// assert!(!misc.rfs());
+    /// let misc: MiscControl = misc.set_rfs(true);
+    /// assert!(misc.rfs());
+    /// assert_eq!(misc, 0x0000_7A01);
+    /// let misc: MiscControl = misc.set_rfs(false);
+    /// assert!(!misc.rfs());
+    /// assert_eq!(misc, MiscControl::RESET);
+    /// ```
+    pub const fn rfs(&self) -> bool {
+        self.0 & Self::RFS_MASK == Self::RFS_MASK
+    }
+    /// ## Set the RFS (Read Frequency Select).
+    #[must_use = "set_rfs returns a modified MiscControl"]
+    pub const fn set_rfs(mut self, rfs: bool) -> Self {
+        match rfs {
+            true => self.0 |= Self::RFS_MASK,
+            false => self.0 &= !Self::RFS_MASK,
+        }
+        self
+    }
+
+    /// ## Get the Return Work Error Flag state.
+    ///
+    /// This returns an `bool` with the `RET_WORK_ERR_FLAG` state, `false` at reset.
+    ///
+    /// The BM1397 documentation does not describe how this bit changes the response stream.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::MiscControl;
+    ///
+    /// let misc: MiscControl = MiscControl::DEFAULT;
+    /// assert!(!misc.ret_work_err_flag());
+    /// let misc: MiscControl = misc.set_ret_work_err_flag(true);
+    /// assert!(misc.ret_work_err_flag());
+    /// assert_eq!(misc.raw() & MiscControl::RET_WORK_ERR_FLAG_MASK, 0x80);
+    /// let misc: MiscControl = misc.set_ret_work_err_flag(false);
+    /// assert!(!misc.ret_work_err_flag());
+    /// ```
+    pub const fn ret_work_err_flag(&self) -> bool {
+        self.0 & Self::RET_WORK_ERR_FLAG_MASK == Self::RET_WORK_ERR_FLAG_MASK
+    }
+    /// ## Set the Return Work Error Flag state.
+    #[must_use = "set_ret_work_err_flag returns a modified MiscControl"]
+    pub const fn set_ret_work_err_flag(mut self, ret_work_err_flag: bool) -> Self {
+        match ret_work_err_flag {
+            true => self.0 |= Self::RET_WORK_ERR_FLAG_MASK,
+            false => self.0 &= !Self::RET_WORK_ERR_FLAG_MASK,
+        }
+        self
+    }
+
+    /// ## Get the SPAT_NOD state.
+    ///
+    /// This returns an `bool` with the `SPAT_NOD` state, `false` at reset.
+    ///
+    /// The BM1397 documentation does not describe the effect of this bit.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::MiscControl;
+    ///
+    /// let misc: MiscControl = MiscControl::DEFAULT;
+    /// assert!(!misc.spat_nod());
+    /// let misc: MiscControl = misc.set_spat_nod(true);
+    /// assert!(misc.spat_nod());
+    /// assert_eq!(misc, 0x0020_3A01);
+    /// let misc: MiscControl = misc.set_spat_nod(false);
+    /// assert!(!misc.spat_nod());
+    /// ```
+    pub const fn spat_nod(&self) -> bool {
+        self.0 & Self::SPAT_NOD_MASK == Self::SPAT_NOD_MASK
+    }
+    /// ## Set the SPAT_NOD state.
+    #[must_use = "set_spat_nod returns a modified MiscControl"]
+    pub const fn set_spat_nod(mut self, spat_nod: bool) -> Self {
+        match spat_nod {
+            true => self.0 |= Self::SPAT_NOD_MASK,
+            false => self.0 &= !Self::SPAT_NOD_MASK,
+        }
+        self
+    }
+
+    /// ## Get the RVS_K0 state.
+    ///
+    /// This returns an `bool` with the `RVS_K0` state, `false` at reset.
+    ///
+    /// Like `SPAT_NOD`, its effect is not documented.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::MiscControl;
+    ///
+    /// let misc: MiscControl = MiscControl::DEFAULT;
+    /// assert!(!misc.rvs_k0());
+    /// let misc: MiscControl = misc.set_rvs_k0(true);
+    /// assert!(misc.rvs_k0());
+    /// assert_eq!(misc, 0x0010_3A01);
+    /// let misc: MiscControl = misc.set_rvs_k0(false);
+    /// assert!(!misc.rvs_k0());
+    /// ```
+    pub const fn rvs_k0(&self) -> bool {
+        self.0 & Self::RVS_K0_MASK == Self::RVS_K0_MASK
+    }
+    /// ## Set the RVS_K0 state.
+    #[must_use = "set_rvs_k0 returns a modified MiscControl"]
+    pub const fn set_rvs_k0(mut self, rvs_k0: bool) -> Self {
+        match rvs_k0 {
+            true => self.0 |= Self::RVS_K0_MASK,
+            false => self.0 &= !Self::RVS_K0_MASK,
+        }
+        self
+    }
 }
 
 impl ::core::fmt::Display for MiscControl {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         f.debug_struct("MiscControl")
+            .field("raw", &format_args!("{:#010x}", self.0))
             .field("bt8d", &self.bt8d())
             .field("core_srst", &self.core_srst())
             .field("bclk_sel", &self.bclk_sel())
+            .field("rfs", &self.rfs())
+            .field("ret_work_err_flag", &self.ret_work_err_flag())
+            .field("spat_nod", &self.spat_nod())
+            .field("rvs_k0", &self.rvs_k0())
             .finish()
     }
 }
@@ -894,10 +1547,15 @@ impl defmt::Format for MiscControl {
     fn format(&self, fmt: defmt::Formatter) {
         defmt::write!(
             fmt,
-            "MiscControl {{ bt8d: {}, core_srst: {}, bclk_sel: {} }}",
+            "MiscControl {{ raw: {=u32:#010x}, bt8d: {}, core_srst: {}, bclk_sel: {}, rfs: {}, ret_work_err_flag: {}, spat_nod: {}, rvs_k0: {} }}",
+            self.0,
             self.bt8d(),
             self.core_srst(),
             self.bclk_sel(),
+            self.rfs(),
+            self.ret_work_err_flag(),
+            self.spat_nod(),
+            self.rvs_k0(),
         );
     }
 }
@@ -906,6 +1564,7 @@ impl defmt::Format for MiscControl {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct I2CControl(u32);
 impl_boilerplate_for!(I2CControl);
 
@@ -962,37 +1621,178 @@ impl I2CControl {
     pub const I2C_REG_ADDR_MASK: u32 = 0xff << Self::I2C_REG_ADDR_OFFSET;
     /// ## Bit mask for the `I2C_REG_VAL` field.
     pub const I2C_REG_VAL_MASK: u32 = 0xff << Self::I2C_REG_VAL_OFFSET;
-}
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !(Self::BUSY_MASK
+        | Self::DO_CMD_MASK
+        | Self::I2C_ADDR_MASK
+        | Self::RD_WR_MASK
+        | Self::I2C_REG_ADDR_MASK
+        | Self::I2C_REG_VAL_MASK);
 
-impl ::core::fmt::Display for I2CControl {
-    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("I2CControl").finish()
+    /// ## Set I2CControl for an I2C Register Read.
+    ///
+    /// Read the `reg_addr` register of the I2C device at `i2c_addr` (7-bits address).
+    /// Following the I2C convention, `RD_WR` is set for a read.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::{I2CControl, Register};
+    ///
+    /// let i2c: I2CControl = I2CControl::DEFAULT.read(0x4C, 0x01);
+    /// assert_eq!(i2c.val(), 0x0199_0100);
+    /// let i2c: I2CControl = I2CControl::DEFAULT.write(0x4C, 0x09, 0x04);
+    /// assert_eq!(i2c.val(), 0x0198_0904);
+    /// ```
+    #[must_use = "read returns a modified I2CControl"]
+    pub const fn read(mut self, i2c_addr: u8, reg_addr: u8) -> Self {
+        self.0 &= !(Self::I2C_ADDR_MASK | Self::I2C_REG_ADDR_MASK | Self::I2C_REG_VAL_MASK);
+        self.0 |= Self::DO_CMD_MASK | Self::RD_WR_MASK;
+        self.0 |= ((i2c_addr as u32) << Self::I2C_ADDR_OFFSET) & Self::I2C_ADDR_MASK;
+        self.0 |= ((reg_addr as u32) << Self::I2C_REG_ADDR_OFFSET) & Self::I2C_REG_ADDR_MASK;
+        self
     }
-}
-
-#[cfg(feature = "defmt")]
-impl defmt::Format for I2CControl {
-    fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "I2CControl {{  }}",);
+    /// ## Set I2CControl for an I2C Register Write.
+    #[must_use = "write returns a modified I2CControl"]
+    pub const fn write(mut self, i2c_addr: u8, reg_addr: u8, reg_val: u8) -> Self {
+        self.0 &= !(Self::I2C_ADDR_MASK
+            | Self::RD_WR_MASK
+            | Self::I2C_REG_ADDR_MASK
+            | Self::I2C_REG_VAL_MASK);
+        self.0 |= Self::DO_CMD_MASK;
+        self.0 |= ((i2c_addr as u32) << Self::I2C_ADDR_OFFSET) & Self::I2C_ADDR_MASK;
+        self.0 |= ((reg_addr as u32) << Self::I2C_REG_ADDR_OFFSET) & Self::I2C_REG_ADDR_MASK;
+        self.0 |= ((reg_val as u32) << Self::I2C_REG_VAL_OFFSET) & Self::I2C_REG_VAL_MASK;
+        self
     }
-}
-
-/// # Ordered Clock Enable register
-///
-/// Used to identify chip.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-pub struct OrderedClockEnable(u32);
-impl_boilerplate_for!(OrderedClockEnable);
 
-impl OrderedClockEnable {
-    /// ## Ordered Clock Enable register address.
+    /// ## Get the BUSY state.
+    ///
+    /// This returns an `bool` with the BUSY state of the I2C master, set while
+    /// an I2C transaction is in progress.
+    ///
+    /// The I2C master is asynchronous, the poll pattern is :
+    /// 1. send `Command::write_reg` of `I2CControl::read` (or `I2CControl::write`),
+    /// 2. send `Command::read_reg` of `I2CControl` and parse the response, repeating
+    ///    while it `is_busy` (with a timeout, a missing I2C device may never answer),
+    /// 3. get the value read from the last, not busy, `I2CControl` with `result`.
     ///
     /// ### Example
     ///
     /// ```
-    /// use bm1397_protocol::register::{OrderedClockEnable, Register};
+    /// use bm1397_protocol::command::{Command, Destination};
+    /// use bm1397_protocol::register::I2CControl;
     ///
-    /// assert_eq!(OrderedClockEnable::ADDR, OrderedClockEnable::DEFAULT.addr());
+    /// let _cmd = Command::write_reg(I2CControl::DEFAULT.read(0x4C, 0x01), Destination::Chip(0));
+    /// let poll = Command::read_reg(I2CControl::DEFAULT, Destination::Chip(0));
+    /// assert_eq!(poll[5], I2CControl::ADDR);
+    /// // responses to successive `poll`
+    /// let mut resps = [I2CControl::from(0x8199_0100), I2CControl::from(0x0099_0132)].into_iter();
+    /// let i2c = resps.find(|i2c| !i2c.is_busy()).unwrap();
+    /// assert_eq!(i2c.result(), Some(0x32));
+    /// assert!(I2CControl::from(0x8199_0100).is_busy());
+    /// assert_eq!(I2CControl::from(0x8199_0100).result(), None);
+    /// ```
+    pub const fn is_busy(&self) -> bool {
+        self.0 & Self::BUSY_MASK == Self::BUSY_MASK
+    }
+
+    /// ## Get the result of the I2C transaction.
+    ///
+    /// This returns an `Option<u8>` with I2C_REG_VAL, or `None` if the I2C transaction
+    /// is still busy. See `is_busy` for the poll pattern.
+    pub const fn result(&self) -> Option<u8> {
+        if self.is_busy() {
+            return None;
+        }
+        Some(self.reg_val())
+    }
+
+    /// ## Get the I2C_REG_VAL.
+    ///
+    /// This returns an `u8` with the value read from the I2C device, once the I2C
+    /// transaction is done.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::I2CControl;
+    ///
+    /// let i2c: I2CControl = I2CControl::from(0x0099_0132);
+    /// assert_eq!(i2c.reg_val(), 0x32);
+    /// ```
+    pub const fn reg_val(&self) -> u8 {
+        ((self.0 & Self::I2C_REG_VAL_MASK) >> Self::I2C_REG_VAL_OFFSET) as u8
+    }
+
+    /// ## Get the temperature read from a temperature sensor.
+    ///
+    /// This returns an `Option<i8>` with the temperature in degrees Celsius, decoding
+    /// `I2C_REG_VAL` as a two's complement integer temperature (like the TMP451 or EMC2101
+    /// temperature registers), or `None` if the I2C transaction is still busy.
+    ///
+    /// This is the second step of a temperature read :
+    /// 1. send `Command::start_temperature_read`.
+    /// 2. send `Command::read_reg` of `I2CControl` until the chip answer with a `I2CControl`
+    ///    that is not busy, and call this method on it.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::I2CControl;
+    ///
+    /// assert_eq!(I2CControl::from(0x0099_0132).temperature(), Some(50));
+    /// assert_eq!(I2CControl::from(0x0099_01F6).temperature(), Some(-10));
+    /// assert_eq!(I2CControl::from(0x8199_0100).temperature(), None);
+    /// ```
+    pub const fn temperature(&self) -> Option<i8> {
+        match self.result() {
+            Some(val) => Some(val as i8),
+            None => None,
+        }
+    }
+}
+
+impl ::core::fmt::Display for I2CControl {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        f.debug_struct("I2CControl")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .field("busy", &self.is_busy())
+            .field("reg_val", &self.reg_val())
+            .finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for I2CControl {
+    fn format(&self, fmt: defmt::Formatter) {
+        defmt::write!(
+            fmt,
+            "I2CControl {{ raw: {=u32:#010x}, busy: {}, reg_val: {} }}",
+            self.0,
+            self.is_busy(),
+            self.reg_val(),
+        );
+    }
+}
+
+/// # Ordered Clock Enable register
+///
+/// Used to identify chip.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OrderedClockEnable(u32);
+impl_boilerplate_for!(OrderedClockEnable);
+
+impl OrderedClockEnable {
+    /// ## Ordered Clock Enable register address.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::{OrderedClockEnable, Register};
+    ///
+    /// assert_eq!(OrderedClockEnable::ADDR, OrderedClockEnable::DEFAULT.addr());
     /// ```
     pub const ADDR: u8 = 0x20;
 
@@ -1017,18 +1817,90 @@ impl OrderedClockEnable {
 
     /// ## Bit mask for the `CLKEN` field.
     pub const CLKEN_MASK: u32 = 0xffff << Self::CLKEN_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !Self::CLKEN_MASK;
+
+    /// ## Get the enable state of an ordered clock.
+    ///
+    /// This returns `false` if `clock` is out of range (greater than 15).
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::OrderedClockEnable;
+    ///
+    /// let clk_en: OrderedClockEnable = OrderedClockEnable::DEFAULT;
+    /// assert!(clk_en.clock_enabled(3));
+    /// let clk_en: OrderedClockEnable = clk_en.disable_clock(3);
+    /// assert!(!clk_en.clock_enabled(3));
+    /// assert_eq!(clk_en, 0x0000_fff7);
+    /// let clk_en: OrderedClockEnable = clk_en.enable_clock(3);
+    /// assert!(clk_en.clock_enabled(3));
+    /// assert!(!clk_en.clock_enabled(16));
+    /// ```
+    pub const fn clock_enabled(&self, clock: u8) -> bool {
+        clock < 16 && self.0 & (1 << (clock + Self::CLKEN_OFFSET)) != 0
+    }
+    /// ## Enable an ordered clock.
+    ///
+    /// A `clock` out of range (greater than 15) is ignored.
+    #[must_use = "enable_clock returns a modified OrderedClockEnable"]
+    pub const fn enable_clock(mut self, clock: u8) -> Self {
+        if clock < 16 {
+            self.0 |= 1 << (clock + Self::CLKEN_OFFSET);
+        }
+        self
+    }
+    /// ## Disable an ordered clock.
+    ///
+    /// A `clock` out of range (greater than 15) is ignored.
+    #[must_use = "disable_clock returns a modified OrderedClockEnable"]
+    pub const fn disable_clock(mut self, clock: u8) -> Self {
+        if clock < 16 {
+            self.0 &= !(1 << (clock + Self::CLKEN_OFFSET));
+        }
+        self
+    }
+
+    /// ## Get the enabled ordered clocks.
+    ///
+    /// This returns an iterator over the indexes of the enabled clocks, in increasing order.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::OrderedClockEnable;
+    ///
+    /// let clk_en = OrderedClockEnable::from(0x0000_8005);
+    /// assert!(clk_en.enabled_clocks().eq([0, 2, 15]));
+    /// assert_eq!(clk_en.disabled_clocks().count(), 13);
+    /// assert_eq!(OrderedClockEnable::DEFAULT.disabled_clocks().next(), None);
+    /// ```
+    pub fn enabled_clocks(&self) -> impl Iterator<Item = u8> {
+        let clk_en = *self;
+        (0..16).filter(move |clock| clk_en.clock_enabled(*clock))
+    }
+    /// ## Get the disabled ordered clocks.
+    ///
+    /// This returns an iterator over the indexes of the disabled clocks, in increasing order.
+    pub fn disabled_clocks(&self) -> impl Iterator<Item = u8> {
+        let clk_en = *self;
+        (0..16).filter(move |clock| !clk_en.clock_enabled(*clock))
+    }
 }
 
 impl ::core::fmt::Display for OrderedClockEnable {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("OrderedClockEnable").finish()
+        f.debug_struct("OrderedClockEnable")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for OrderedClockEnable {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "OrderedClockEnable {{  }}",);
+        defmt::write!(fmt, "OrderedClockEnable {{ raw: {=u32:#010x} }}", self.0);
     }
 }
 
@@ -1036,6 +1908,7 @@ impl defmt::Format for OrderedClockEnable {
 ///
 /// Used to configure UART settings.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FastUARTConfiguration(u32);
 impl_boilerplate_for!(FastUARTConfiguration);
 
@@ -1100,6 +1973,15 @@ impl FastUARTConfiguration {
     pub const CLKO_ODDSET_MASK: u32 = 0b11 << Self::CLKO_ODDSET_OFFSET;
     /// ## Bit mask for the `CLKO_DIV` field.
     pub const CLKO_DIV_MASK: u32 = 0xff << Self::CLKO_DIV_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !(Self::DIV4_ODDSET_MASK
+        | Self::PLL3_DIV4_MASK
+        | Self::USRC_ODDSET_MASK
+        | Self::USRC_DIV_MASK
+        | Self::FORCE_CORE_EN_MASK
+        | Self::CLKO_SEL_MASK
+        | Self::CLKO_ODDSET_MASK
+        | Self::CLKO_DIV_MASK);
 
     /// ## Get the PLL3_DIV4.
     ///
@@ -1127,12 +2009,250 @@ impl FastUARTConfiguration {
         self.0 |= ((pll3_div4 as u32) << Self::PLL3_DIV4_OFFSET) & Self::PLL3_DIV4_MASK;
         self
     }
+
+    /// ## Get the DIV4_ODDSET.
+    ///
+    /// This returns an `u8` with the 2-bits DIV4_ODDSET value, `0` at reset.
+    ///
+    /// The BM1397 documentation does not describe what the DIV4_ODDSET values do.
+    /// `Command::set_baudrate` leaves it at `0`, with a `PLL3_DIV4` of 6. The effect
+    /// of the values `1` to `3` is unknown.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::FastUARTConfiguration;
+    ///
+    /// // the fast UART configuration sent by `Command::set_baudrate`
+    /// let uart_conf = FastUARTConfiguration::DEFAULT.set_pll3_div4(6);
+    /// assert_eq!(uart_conf, 0x0600_000F);
+    /// assert_eq!(uart_conf.div4_oddset(), 0x00);
+    /// let uart_conf = uart_conf.set_div4_oddset(0x02);
+    /// assert_eq!(uart_conf.div4_oddset(), 0x02);
+    /// assert_eq!(uart_conf, 0x8600_000F);
+    /// let uart_conf = uart_conf.set_div4_oddset(0xF5);
+    /// assert_eq!(uart_conf.div4_oddset(), 0x01);
+    /// ```
+    pub const fn div4_oddset(&self) -> u8 {
+        ((self.0 & Self::DIV4_ODDSET_MASK) >> Self::DIV4_ODDSET_OFFSET) as u8
+    }
+    /// ## Set the DIV4_ODDSET.
+    #[must_use = "set_div4_oddset returns a modified FastUARTConfiguration"]
+    pub const fn set_div4_oddset(mut self, div4_oddset: u8) -> Self {
+        self.0 &= !Self::DIV4_ODDSET_MASK;
+        self.0 |= ((div4_oddset as u32) << Self::DIV4_ODDSET_OFFSET) & Self::DIV4_ODDSET_MASK;
+        self
+    }
+
+    /// ## Get the USRC_DIV.
+    ///
+    /// This returns an `u8` with the 6-bits USRC_DIV value.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::FastUARTConfiguration;
+    ///
+    /// let uart_conf: FastUARTConfiguration = FastUARTConfiguration::DEFAULT;
+    /// assert_eq!(uart_conf.usrc_div(), 0x00);
+    /// let uart_conf: FastUARTConfiguration = uart_conf.set_usrc_div(0x2A);
+    /// assert_eq!(uart_conf.usrc_div(), 0x2A);
+    /// let uart_conf: FastUARTConfiguration = uart_conf.set_usrc_div(0xF5);
+    /// assert_eq!(uart_conf.usrc_div(), 0x35);
+    /// ```
+    pub const fn usrc_div(&self) -> u8 {
+        ((self.0 & Self::USRC_DIV_MASK) >> Self::USRC_DIV_OFFSET) as u8
+    }
+    /// ## Set the USRC_DIV.
+    #[must_use = "set_usrc_div returns a modified FastUARTConfiguration"]
+    pub const fn set_usrc_div(mut self, usrc_div: u8) -> Self {
+        self.0 &= !Self::USRC_DIV_MASK;
+        self.0 |= ((usrc_div as u32) << Self::USRC_DIV_OFFSET) & Self::USRC_DIV_MASK;
+        self
+    }
+
+    /// ## Get the USRC_ODDSET.
+    ///
+    /// This returns an `u8` with the 2-bits USRC_ODDSET value.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::FastUARTConfiguration;
+    ///
+    /// let uart_conf: FastUARTConfiguration = FastUARTConfiguration::DEFAULT;
+    /// assert_eq!(uart_conf.usrc_oddset(), 0x00);
+    /// let uart_conf: FastUARTConfiguration = uart_conf.set_usrc_oddset(0x02);
+    /// assert_eq!(uart_conf.usrc_oddset(), 0x02);
+    /// let uart_conf: FastUARTConfiguration = uart_conf.set_usrc_oddset(0xF5);
+    /// assert_eq!(uart_conf.usrc_oddset(), 0x01);
+    /// ```
+    pub const fn usrc_oddset(&self) -> u8 {
+        ((self.0 & Self::USRC_ODDSET_MASK) >> Self::USRC_ODDSET_OFFSET) as u8
+    }
+    /// ## Set the USRC_ODDSET.
+    #[must_use = "set_usrc_oddset returns a modified FastUARTConfiguration"]
+    pub const fn set_usrc_oddset(mut self, usrc_oddset: u8) -> Self {
+        self.0 &= !Self::USRC_ODDSET_MASK;
+        self.0 |= ((usrc_oddset as u32) << Self::USRC_ODDSET_OFFSET) & Self::USRC_ODDSET_MASK;
+        self
+    }
+
+    /// ## Set the USRC (user clock) configuration.
+    ///
+    /// Same as `set_usrc_div` followed by `set_usrc_oddset`, the values are masked
+    /// to their 6-bits and 2-bits range.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::FastUARTConfiguration;
+    ///
+    /// let uart_conf = FastUARTConfiguration::DEFAULT.set_usrc(0x05, 0x01);
+    /// assert_eq!(uart_conf.usrc_div(), 0x05);
+    /// assert_eq!(uart_conf.usrc_oddset(), 0x01);
+    /// ```
+    #[must_use = "set_usrc returns a modified FastUARTConfiguration"]
+    pub const fn set_usrc(self, usrc_div: u8, usrc_oddset: u8) -> Self {
+        self.set_usrc_div(usrc_div).set_usrc_oddset(usrc_oddset)
+    }
+
+    /// ## Get the USRC Frequency.
+    ///
+    /// This returns an `HertzU32` with the USRC (user clock) Frequency according to the
+    /// `src` clock frequency : `src / (USRC_DIV + 1)`.
+    /// The USRC_ODDSET only adjust the duty cycle, not the frequency.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::FastUARTConfiguration;
+    /// use fugit::HertzU32;
+    ///
+    /// let uart_conf = FastUARTConfiguration::DEFAULT.set_usrc(0x04, 0x00);
+    /// assert_eq!(uart_conf.usrc_frequency(HertzU32::MHz(400)), HertzU32::MHz(80));
+    /// ```
+    pub const fn usrc_frequency(&self, src: HertzU32) -> HertzU32 {
+        HertzU32::from_raw(src.raw() / (self.usrc_div() as u32 + 1))
+    }
+
+    /// ## Get the CLKO_SEL.
+    ///
+    /// This returns an `bool` with the CLKO_SEL state, selecting the source clock
+    /// divided on the CLKO pin. The source of each setting is not documented,
+    /// it must be checked with a scope against a known PLL frequency.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::FastUARTConfiguration;
+    ///
+    /// let uart_conf: FastUARTConfiguration = FastUARTConfiguration::DEFAULT;
+    /// assert!(!uart_conf.clko_sel());
+    /// let uart_conf: FastUARTConfiguration = uart_conf.set_clko_sel(true);
+    /// assert!(uart_conf.clko_sel());
+    /// assert_eq!(uart_conf, 0x0600_400F);
+    /// ```
+    pub const fn clko_sel(&self) -> bool {
+        self.0 & Self::CLKO_SEL_MASK == Self::CLKO_SEL_MASK
+    }
+    /// ## Set the CLKO_SEL.
+    #[must_use = "set_clko_sel returns a modified FastUARTConfiguration"]
+    pub const fn set_clko_sel(mut self, clko_sel: bool) -> Self {
+        match clko_sel {
+            true => self.0 |= Self::CLKO_SEL_MASK,
+            false => self.0 &= !Self::CLKO_SEL_MASK,
+        }
+        self
+    }
+
+    /// ## Get the CLKO_ODDSET.
+    ///
+    /// This returns an `u8` with the 2-bits CLKO_ODDSET value, adjusting the duty cycle
+    /// of the CLKO output like `div4_oddset`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::FastUARTConfiguration;
+    ///
+    /// let uart_conf: FastUARTConfiguration = FastUARTConfiguration::DEFAULT;
+    /// assert_eq!(uart_conf.clko_oddset(), 0x00);
+    /// let uart_conf: FastUARTConfiguration = uart_conf.set_clko_oddset(0x02);
+    /// assert_eq!(uart_conf.clko_oddset(), 0x02);
+    /// let uart_conf: FastUARTConfiguration = uart_conf.set_clko_oddset(0xF5);
+    /// assert_eq!(uart_conf.clko_oddset(), 0x01);
+    /// ```
+    pub const fn clko_oddset(&self) -> u8 {
+        ((self.0 & Self::CLKO_ODDSET_MASK) >> Self::CLKO_ODDSET_OFFSET) as u8
+    }
+    /// ## Set the CLKO_ODDSET.
+    #[must_use = "set_clko_oddset returns a modified FastUARTConfiguration"]
+    pub const fn set_clko_oddset(mut self, clko_oddset: u8) -> Self {
+        self.0 &= !Self::CLKO_ODDSET_MASK;
+        self.0 |= ((clko_oddset as u32) << Self::CLKO_ODDSET_OFFSET) & Self::CLKO_ODDSET_MASK;
+        self
+    }
+
+    /// ## Get the CLKO_DIV.
+    ///
+    /// This returns an `u8` with the 8-bits CLKO_DIV value.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::FastUARTConfiguration;
+    ///
+    /// let uart_conf: FastUARTConfiguration = FastUARTConfiguration::DEFAULT;
+    /// assert_eq!(uart_conf.clko_div(), 0x0F);
+    /// let uart_conf: FastUARTConfiguration = uart_conf.set_clko_div(0xA5);
+    /// assert_eq!(uart_conf.clko_div(), 0xA5);
+    /// assert_eq!(uart_conf, 0x0600_00A5);
+    /// ```
+    pub const fn clko_div(&self) -> u8 {
+        ((self.0 & Self::CLKO_DIV_MASK) >> Self::CLKO_DIV_OFFSET) as u8
+    }
+    /// ## Set the CLKO_DIV.
+    #[must_use = "set_clko_div returns a modified FastUARTConfiguration"]
+    pub const fn set_clko_div(mut self, clko_div: u8) -> Self {
+        self.0 &= !Self::CLKO_DIV_MASK;
+        self.0 |= ((clko_div as u32) << Self::CLKO_DIV_OFFSET) & Self::CLKO_DIV_MASK;
+        self
+    }
+
+    /// ## Get the CLKO Frequency.
+    ///
+    /// This returns an `HertzU32` with the CLKO output Frequency according to the
+    /// `src` clock frequency selected by CLKO_SEL : `src / (CLKO_DIV + 1)`.
+    /// The CLKO_ODDSET only adjust the duty cycle, not the frequency.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::FastUARTConfiguration;
+    /// use fugit::HertzU32;
+    ///
+    /// let uart_conf = FastUARTConfiguration::DEFAULT;
+    /// assert_eq!(uart_conf.clko_frequency(HertzU32::MHz(400)), HertzU32::MHz(25));
+    /// let uart_conf = uart_conf.set_clko_div(99);
+    /// assert_eq!(uart_conf.clko_frequency(HertzU32::MHz(400)), HertzU32::MHz(4));
+    /// ```
+    pub const fn clko_frequency(&self, src: HertzU32) -> HertzU32 {
+        HertzU32::from_raw(src.raw() / (self.clko_div() as u32 + 1))
+    }
 }
 
 impl ::core::fmt::Display for FastUARTConfiguration {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         f.debug_struct("FastUARTConfiguration")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .field("div4_oddset", &self.div4_oddset())
             .field("pll3_div4", &self.pll3_div4())
+            .field("usrc_oddset", &self.usrc_oddset())
+            .field("usrc_div", &self.usrc_div())
+            .field("clko_sel", &self.clko_sel())
+            .field("clko_oddset", &self.clko_oddset())
+            .field("clko_div", &self.clko_div())
             .finish()
     }
 }
@@ -1142,8 +2262,15 @@ impl defmt::Format for FastUARTConfiguration {
     fn format(&self, fmt: defmt::Formatter) {
         defmt::write!(
             fmt,
-            "FastUARTConfiguration {{ pll3_div4: {} }}",
+            "FastUARTConfiguration {{ raw: {=u32:#010x}, div4_oddset: {}, pll3_div4: {}, usrc_oddset: {}, usrc_div: {}, clko_sel: {}, clko_oddset: {}, clko_div: {} }}",
+            self.0,
+            self.div4_oddset(),
             self.pll3_div4(),
+            self.usrc_oddset(),
+            self.usrc_div(),
+            self.clko_sel(),
+            self.clko_oddset(),
+            self.clko_div(),
         );
     }
 }
@@ -1152,6 +2279,7 @@ impl defmt::Format for FastUARTConfiguration {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UARTRelay(u32);
 impl_boilerplate_for!(UARTRelay);
 
@@ -1196,18 +2324,23 @@ impl UARTRelay {
     pub const RO_REL_EN_MASK: u32 = 0b1 << Self::RO_REL_EN_OFFSET;
     /// ## Bit mask for the `CO_REL_EN` field.
     pub const CO_REL_EN_MASK: u32 = 0b1 << Self::CO_REL_EN_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 =
+        !(Self::GAP_CNT_MASK | Self::RO_REL_EN_MASK | Self::CO_REL_EN_MASK);
 }
 
 impl ::core::fmt::Display for UARTRelay {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("UARTRelay").finish()
+        f.debug_struct("UARTRelay")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for UARTRelay {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "UARTRelay {{  }}",);
+        defmt::write!(fmt, "UARTRelay {{ raw: {=u32:#010x} }}", self.0);
     }
 }
 
@@ -1215,6 +2348,7 @@ impl defmt::Format for UARTRelay {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TicketMask2(u32);
 impl_boilerplate_for!(TicketMask2);
 
@@ -1251,18 +2385,22 @@ impl TicketMask2 {
 
     /// ## Bit mask for the `TM` field.
     pub const TM_MASK: u32 = 0xffff_ffff << Self::TM_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !Self::TM_MASK;
 }
 
 impl ::core::fmt::Display for TicketMask2 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("TicketMask2").finish()
+        f.debug_struct("TicketMask2")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for TicketMask2 {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "TicketMask2 {{  }}",);
+        defmt::write!(fmt, "TicketMask2 {{ raw: {=u32:#010x} }}", self.0);
     }
 }
 
@@ -1270,6 +2408,7 @@ impl defmt::Format for TicketMask2 {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CoreRegisterControl(u32);
 impl_boilerplate_for!(CoreRegisterControl);
 
@@ -1313,6 +2452,9 @@ impl CoreRegisterControl {
     pub const CORE_REG_VAL_OFFSET: u8 = 0;
 
     /// ## Bit mask for the `RD_WR` field.
+    ///
+    /// The direction is a single logical bit duplicated in `RD_WR1` and `RD_WR2`,
+    /// so `read` and `write` always set both together.
     pub const RD_WR_MASK: u32 = 0b1 << Self::RD_WR1_OFFSET | 0b1 << Self::RD_WR2_OFFSET;
     /// ## Bit mask for the `CORE_ID` field.
     pub const CORE_ID_MASK: u32 = 0xff << Self::CORE_ID_OFFSET;
@@ -1320,6 +2462,73 @@ impl CoreRegisterControl {
     pub const CORE_REG_ID_MASK: u32 = 0b1111 << Self::CORE_REG_ID_OFFSET;
     /// ## Bit mask for the `CORE_REG_VAL` field.
     pub const CORE_REG_VAL_MASK: u32 = 0xff << Self::CORE_REG_VAL_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 =
+        !(Self::RD_WR_MASK | Self::CORE_ID_MASK | Self::CORE_REG_ID_MASK | Self::CORE_REG_VAL_MASK);
+
+    /// ## Get the CORE_ID.
+    ///
+    /// This returns an `u8` with the CORE_ID value.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::CoreRegisterControl;
+    /// use bm1397_protocol::core_register::HashClockCounter;
+    ///
+    /// let crc = CoreRegisterControl::DEFAULT.read(0x12, HashClockCounter::default());
+    /// assert_eq!(crc.core_id(), 0x12);
+    /// ```
+    pub const fn core_id(&self) -> u8 {
+        ((self.0 & Self::CORE_ID_MASK) >> Self::CORE_ID_OFFSET) as u8
+    }
+
+    /// ## Get the CORE_REG_ID.
+    ///
+    /// This returns an `u8` with the CORE_REG_ID value.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::CoreRegisterControl;
+    /// use bm1397_protocol::core_register::HashClockCounter;
+    ///
+    /// let crc = CoreRegisterControl::DEFAULT.read(0x12, HashClockCounter::default());
+    /// assert_eq!(crc.core_reg_id(), HashClockCounter::ID);
+    /// ```
+    pub const fn core_reg_id(&self) -> u8 {
+        ((self.0 & Self::CORE_REG_ID_MASK) >> Self::CORE_REG_ID_OFFSET) as u8
+    }
+
+    /// ## Get the direction.
+    ///
+    /// This returns an `Option<bool>` with the direction :
+    /// - `Some(true)` for a Core Register Write, both `RD_WR1` and `RD_WR2` are set.
+    /// - `Some(false)` for a Core Register Read, both `RD_WR1` and `RD_WR2` are cleared.
+    /// - `None` if `RD_WR1` and `RD_WR2` disagree, a sign of a corrupted value.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::CoreRegisterControl;
+    /// use bm1397_protocol::core_register::HashClockCounter;
+    ///
+    /// let crc = CoreRegisterControl::DEFAULT.read(0, HashClockCounter::default());
+    /// assert_eq!(crc.direction(), Some(false));
+    /// let crc = CoreRegisterControl::DEFAULT.write(0, HashClockCounter::default());
+    /// assert_eq!(crc.direction(), Some(true));
+    /// let crc = CoreRegisterControl::from(0x8000_0000);
+    /// assert_eq!(crc.direction(), None);
+    /// ```
+    pub const fn direction(&self) -> Option<bool> {
+        let rd_wr1 = self.0 & (1 << Self::RD_WR1_OFFSET) != 0;
+        let rd_wr2 = self.0 & (1 << Self::RD_WR2_OFFSET) != 0;
+        if rd_wr1 == rd_wr2 {
+            Some(rd_wr1)
+        } else {
+            None
+        }
+    }
 
     /// ## Set CoreRegisterControl for a Core Register Read.
     ///
@@ -1339,12 +2548,17 @@ impl CoreRegisterControl {
     /// assert_eq!(crc.val(), 0x8000_8004);
     /// ```
     #[must_use = "read returns a modified CoreRegisterControl"]
-    pub fn read(mut self, core_id: u8, core_reg: impl CoreRegister) -> Self {
+    pub fn read(self, core_id: u8, core_reg: impl CoreRegister) -> Self {
+        self.read_id(core_id, core_reg.id())
+    }
+    /// ## Set CoreRegisterControl for a Core Register Read, by core register id.
+    #[must_use = "read_id returns a modified CoreRegisterControl"]
+    pub(crate) const fn read_id(mut self, core_id: u8, core_reg_id: u8) -> Self {
         self.0 &= !Self::RD_WR_MASK;
         self.0 &= !Self::CORE_ID_MASK;
         self.0 |= ((core_id as u32) << Self::CORE_ID_OFFSET) & Self::CORE_ID_MASK;
         self.0 &= !Self::CORE_REG_ID_MASK;
-        self.0 |= ((core_reg.id() as u32) << Self::CORE_REG_ID_OFFSET) & Self::CORE_REG_ID_MASK;
+        self.0 |= ((core_reg_id as u32) << Self::CORE_REG_ID_OFFSET) & Self::CORE_REG_ID_MASK;
         self.0 |= Self::CORE_REG_VAL_MASK;
         self
     }
@@ -1364,14 +2578,16 @@ impl CoreRegisterControl {
 
 impl ::core::fmt::Display for CoreRegisterControl {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("CoreRegisterControl").finish()
+        f.debug_struct("CoreRegisterControl")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for CoreRegisterControl {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "CoreRegisterControl {{  }}",);
+        defmt::write!(fmt, "CoreRegisterControl {{ raw: {=u32:#010x} }}", self.0);
     }
 }
 
@@ -1379,6 +2595,7 @@ impl defmt::Format for CoreRegisterControl {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CoreRegisterValue(u32);
 impl_boilerplate_for!(CoreRegisterValue);
 
@@ -1423,6 +2640,9 @@ impl CoreRegisterValue {
     pub const FOUND_MASK: u32 = 0xff << Self::FOUND_OFFSET;
     /// ## Bit mask for the `CORE_REG_VAL` field.
     pub const CORE_REG_VAL_MASK: u32 = 0xff << Self::CORE_REG_VAL_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 =
+        !(Self::CORE_ID_MASK | Self::FOUND_MASK | Self::CORE_REG_VAL_MASK);
 
     /// ## Get the CORE_ID.
     ///
@@ -1444,6 +2664,10 @@ impl CoreRegisterValue {
     ///
     /// This returns an `u8` with the FOUND value.
     ///
+    /// During a pattern test, FOUND count how many of the expected pattern values
+    /// the core `CORE_ID` matched. Outside of a pattern test its meaning is not documented.
+    /// See `all_found` to validate a core.
+    ///
     /// ### Example
     ///
     /// ```
@@ -1456,6 +2680,26 @@ impl CoreRegisterValue {
         ((self.0 & Self::FOUND_MASK) >> Self::FOUND_OFFSET) as u8
     }
 
+    /// ## Check if all the expected pattern values were found.
+    ///
+    /// This returns `true` if FOUND is exactly the `expected` count of pattern values
+    /// sent during the pattern test, ie the core `CORE_ID` matched all of them.
+    /// A FOUND greater than `expected` is not valid and also return `false`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::CoreRegisterValue;
+    ///
+    /// let crv: CoreRegisterValue = CoreRegisterValue::from(0x0001_0434);
+    /// assert!(crv.all_found(4));
+    /// assert!(!crv.all_found(8));
+    /// assert!(!crv.all_found(2));
+    /// ```
+    pub const fn all_found(&self, expected: u8) -> bool {
+        self.found() == expected
+    }
+
     /// ## Get the CORE_REG_VAL.
     ///
     /// This returns an `u8` with the CORE_REG_VAL value.
@@ -1478,7 +2722,7 @@ impl CoreRegisterValue {
     /// ## Return
     /// - `Ok(CoreRegisters)` with the corresponding `CoreRegister`.
     /// - `Err(Error::UnknownCoreRegister(u8))` with the core register id
-    ///    if it do not match a known `CoreRegisters`.
+    ///   if it do not match a known `CoreRegisters`.
     ///
     /// ### Examples
     /// ```
@@ -1523,18 +2767,86 @@ impl CoreRegisterValue {
         };
         Ok(core_reg)
     }
+
+    /// ## Get the CoreRegister according to a CoreRegisterControl echo.
+    ///
+    /// A `CoreRegisterValue` response do not include the core register id,
+    /// but the `CoreRegisterControl` register still hold the CORE_REG_ID of the last
+    /// core register access, so it can be read back to decode the `CoreRegisterValue`.
+    ///
+    /// The required read ordering is :
+    /// 1. send `Command::read_core_reg`, the chip answer with a `CoreRegisterValue` response.
+    /// 2. send `Command::read_reg` of `CoreRegisterControl`, before any other core register access.
+    /// 3. call this method on the `CoreRegisterValue` with the `CoreRegisterControl` response.
+    ///
+    /// ## Return
+    /// - `Ok(CoreRegisters)` with the corresponding `CoreRegister`.
+    /// - `Err(Error::UnknownCoreRegister(u8))` with the core register id
+    ///   if it do not match a known `CoreRegisters`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::core_register::{CoreError, CoreRegisters};
+    /// use bm1397_protocol::register::{CoreRegisterControl, CoreRegisterValue};
+    ///
+    /// let crc: CoreRegisterControl = CoreRegisterControl::DEFAULT.read(0, CoreError::default());
+    /// let crv: CoreRegisterValue = CoreRegisterValue::from(0x0000_0012);
+    /// assert_eq!(
+    ///     crv.core_reg_from_ctrl(&crc),
+    ///     Ok(CoreRegisters::CoreError(CoreError::from(0x12)))
+    /// );
+    /// ```
+    pub fn core_reg_from_ctrl(&self, ctrl: &CoreRegisterControl) -> Result<CoreRegisters, Error> {
+        self.core_reg(ctrl.core_reg_id())
+    }
+
+    /// ## Get all the CoreRegisters read by `Command::read_all_core_regs`.
+    ///
+    /// The `CoreRegisterValue` responses do not include the core register id, so they
+    /// must be given in the same order as the commands issued by `Command::read_all_core_regs`,
+    /// that is the `CORE_REGISTER_IDS` order.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::core_register::{ClockDelayCtrl, CoreRegisters, ProcessMonitorCtrl};
+    /// use bm1397_protocol::register::CoreRegisterValue;
+    ///
+    /// let crvs = [CoreRegisterValue::from(0x0000_0008), CoreRegisterValue::from(0x0000_0001)];
+    /// let mut core_regs = CoreRegisterValue::all_core_regs(crvs);
+    /// assert_eq!(
+    ///     core_regs.next(),
+    ///     Some(CoreRegisters::ClockDelayCtrl(ClockDelayCtrl::from(0x08)))
+    /// );
+    /// assert_eq!(
+    ///     core_regs.next(),
+    ///     Some(CoreRegisters::ProcessMonitorCtrl(ProcessMonitorCtrl::from(0x01)))
+    /// );
+    /// assert_eq!(core_regs.next(), None);
+    /// ```
+    pub fn all_core_regs(
+        values: impl IntoIterator<Item = CoreRegisterValue>,
+    ) -> impl Iterator<Item = CoreRegisters> {
+        CORE_REGISTER_IDS
+            .iter()
+            .zip(values)
+            .filter_map(|(id, crv)| crv.core_reg(*id).ok())
+    }
 }
 
 impl ::core::fmt::Display for CoreRegisterValue {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("CoreRegisterValue").finish()
+        f.debug_struct("CoreRegisterValue")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for CoreRegisterValue {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "CoreRegisterValue {{  }}",);
+        defmt::write!(fmt, "CoreRegisterValue {{ raw: {=u32:#010x} }}", self.0);
     }
 }
 
@@ -1542,6 +2854,7 @@ impl defmt::Format for CoreRegisterValue {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ExternalTemperatureSensorRead(u32);
 impl_boilerplate_for!(ExternalTemperatureSensorRead);
 
@@ -1590,18 +2903,114 @@ impl ExternalTemperatureSensorRead {
     pub const EXTERNAL_TEMP_ADDR_MASK: u32 = 0xff << Self::EXTERNAL_TEMP_ADDR_OFFSET;
     /// ## Bit mask for the `EXTERNAL_TEMP_DATA` field.
     pub const EXTERNAL_TEMP_DATA_MASK: u32 = 0xff << Self::EXTERNAL_TEMP_DATA_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !(Self::LOCAL_TEMP_ADDR_MASK
+        | Self::LOCAL_TEMP_DATA_MASK
+        | Self::EXTERNAL_TEMP_ADDR_MASK
+        | Self::EXTERNAL_TEMP_DATA_MASK);
+
+    /// ## Get the LOCAL_TEMP_ADDR.
+    ///
+    /// This returns an `u8` with the sensor register address of the local temperature.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::ExternalTemperatureSensorRead;
+    ///
+    /// let etsr = ExternalTemperatureSensorRead::from(0x0032_0137);
+    /// assert_eq!(etsr.local_temp_addr(), 0x00);
+    /// assert_eq!(etsr.local_temp(), 50);
+    /// assert_eq!(etsr.external_temp_addr(), 0x01);
+    /// assert_eq!(etsr.external_temp(), 55);
+    /// ```
+    pub const fn local_temp_addr(&self) -> u8 {
+        ((self.0 & Self::LOCAL_TEMP_ADDR_MASK) >> Self::LOCAL_TEMP_ADDR_OFFSET) as u8
+    }
+    /// ## Set the LOCAL_TEMP_ADDR.
+    #[must_use = "set_local_temp_addr returns a modified ExternalTemperatureSensorRead"]
+    pub const fn set_local_temp_addr(mut self, addr: u8) -> Self {
+        self.0 &= !Self::LOCAL_TEMP_ADDR_MASK;
+        self.0 |= ((addr as u32) << Self::LOCAL_TEMP_ADDR_OFFSET) & Self::LOCAL_TEMP_ADDR_MASK;
+        self
+    }
+
+    /// ## Get the local temperature.
+    ///
+    /// This returns an `i8` with the local temperature in degrees Celsius.
+    pub const fn local_temp(&self) -> i8 {
+        ((self.0 & Self::LOCAL_TEMP_DATA_MASK) >> Self::LOCAL_TEMP_DATA_OFFSET) as u8 as i8
+    }
+
+    /// ## Get the EXTERNAL_TEMP_ADDR.
+    ///
+    /// This returns an `u8` with the sensor register address of the external temperature.
+    pub const fn external_temp_addr(&self) -> u8 {
+        ((self.0 & Self::EXTERNAL_TEMP_ADDR_MASK) >> Self::EXTERNAL_TEMP_ADDR_OFFSET) as u8
+    }
+    /// ## Set the EXTERNAL_TEMP_ADDR.
+    ///
+    /// Select the register of the external sensor holding the remote temperature.
+    /// Only the `*_TEMP_ADDR` fields are configuration, the `*_TEMP_DATA` ones are
+    /// updated by the chip.
+    ///
+    /// The data of a single sensor register can also be read through `I2CControl` :
+    /// write it with `I2CControl::read` (see `Command::start_temperature_read`),
+    /// then poll `I2CControl` until not busy and decode it with `I2CControl::temperature`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::command::{Command, Destination};
+    /// use bm1397_protocol::register::ExternalTemperatureSensorRead;
+    ///
+    /// // remote temperature register of the sensor
+    /// let etsr = ExternalTemperatureSensorRead::from(0).set_external_temp_addr(0x01);
+    /// assert_eq!(etsr.external_temp_addr(), 0x01);
+    /// assert_eq!(etsr, 0x0000_0100);
+    /// let cmd = Command::write_reg(etsr, Destination::Chip(0));
+    /// assert_eq!(cmd[6..10], [0x00, 0x00, 0x01, 0x00]);
+    /// ```
+    #[must_use = "set_external_temp_addr returns a modified ExternalTemperatureSensorRead"]
+    pub const fn set_external_temp_addr(mut self, addr: u8) -> Self {
+        self.0 &= !Self::EXTERNAL_TEMP_ADDR_MASK;
+        self.0 |=
+            ((addr as u32) << Self::EXTERNAL_TEMP_ADDR_OFFSET) & Self::EXTERNAL_TEMP_ADDR_MASK;
+        self
+    }
+
+    /// ## Get the external temperature.
+    ///
+    /// This returns an `i8` with the external temperature in degrees Celsius.
+    pub const fn external_temp(&self) -> i8 {
+        ((self.0 & Self::EXTERNAL_TEMP_DATA_MASK) >> Self::EXTERNAL_TEMP_DATA_OFFSET) as u8 as i8
+    }
 }
 
 impl ::core::fmt::Display for ExternalTemperatureSensorRead {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("ExternalTemperatureSensorRead").finish()
+        f.debug_struct("ExternalTemperatureSensorRead")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .field("local_temp_addr", &self.local_temp_addr())
+            .field("local_temp", &self.local_temp())
+            .field("external_temp_addr", &self.external_temp_addr())
+            .field("external_temp", &self.external_temp())
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for ExternalTemperatureSensorRead {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "ExternalTemperatureSensorRead {{  }}",);
+        defmt::write!(
+            fmt,
+            "ExternalTemperatureSensorRead {{ raw: {=u32:#010x}, local_temp_addr: {}, local_temp: {}, external_temp_addr: {}, external_temp: {} }}",
+            self.0,
+            self.local_temp_addr(),
+            self.local_temp(),
+            self.external_temp_addr(),
+            self.external_temp(),
+        );
     }
 }
 
@@ -1609,6 +3018,7 @@ impl defmt::Format for ExternalTemperatureSensorRead {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ErrorFlag(u32);
 impl_boilerplate_for!(ErrorFlag);
 
@@ -1653,18 +3063,80 @@ impl ErrorFlag {
     pub const WORK_ERR_CNT_MASK: u32 = 0xff << Self::WORK_ERR_CNT_OFFSET;
     /// ## Bit mask for the `CORE_RESP_ERR` field.
     pub const CORE_RESP_ERR_MASK: u32 = 0xff << Self::CORE_RESP_ERR_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 =
+        !(Self::CMD_ERR_CNT_MASK | Self::WORK_ERR_CNT_MASK | Self::CORE_RESP_ERR_MASK);
+
+    /// ## Get the CMD_ERR_CNT.
+    ///
+    /// This returns an `u8` with the command error counter value.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::ErrorFlag;
+    ///
+    /// assert_eq!(ErrorFlag::DEFAULT.cmd_err_cnt(), 0xFF);
+    /// assert_eq!(ErrorFlag::from(0x1203_0045).cmd_err_cnt(), 0x12);
+    /// ```
+    pub const fn cmd_err_cnt(&self) -> u8 {
+        ((self.0 & Self::CMD_ERR_CNT_MASK) >> Self::CMD_ERR_CNT_OFFSET) as u8
+    }
+
+    /// ## Get the WORK_ERR_CNT.
+    ///
+    /// This returns an `u8` with the work error counter value.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::ErrorFlag;
+    ///
+    /// assert_eq!(ErrorFlag::DEFAULT.work_err_cnt(), 0x00);
+    /// assert_eq!(ErrorFlag::from(0x1203_0045).work_err_cnt(), 0x03);
+    /// ```
+    pub const fn work_err_cnt(&self) -> u8 {
+        ((self.0 & Self::WORK_ERR_CNT_MASK) >> Self::WORK_ERR_CNT_OFFSET) as u8
+    }
+
+    /// ## Get the CORE_RESP_ERR.
+    ///
+    /// This returns an `u8` with the CORE_RESP_ERR value.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::ErrorFlag;
+    ///
+    /// assert_eq!(ErrorFlag::from(0x1203_0045).core_resp_err(), 0x45);
+    /// ```
+    pub const fn core_resp_err(&self) -> u8 {
+        ((self.0 & Self::CORE_RESP_ERR_MASK) >> Self::CORE_RESP_ERR_OFFSET) as u8
+    }
 }
 
 impl ::core::fmt::Display for ErrorFlag {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("ErrorFlag").finish()
+        f.debug_struct("ErrorFlag")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .field("cmd_err_cnt", &self.cmd_err_cnt())
+            .field("work_err_cnt", &self.work_err_cnt())
+            .field("core_resp_err", &self.core_resp_err())
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for ErrorFlag {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "ErrorFlag {{  }}",);
+        defmt::write!(
+            fmt,
+            "ErrorFlag {{ raw: {=u32:#010x}, cmd_err_cnt: {}, work_err_cnt: {}, core_resp_err: {} }}",
+            self.0,
+            self.cmd_err_cnt(),
+            self.work_err_cnt(),
+            self.core_resp_err(),
+        );
     }
 }
 
@@ -1672,6 +3144,7 @@ impl defmt::Format for ErrorFlag {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NonceErrorCounter(u32);
 impl_boilerplate_for!(NonceErrorCounter);
 
@@ -1708,18 +3181,22 @@ impl NonceErrorCounter {
 
     /// ## Bit mask for the `ERR_CNT` field.
     pub const ERR_CNT_MASK: u32 = 0xffff_ffff << Self::ERR_CNT_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !Self::ERR_CNT_MASK;
 }
 
 impl ::core::fmt::Display for NonceErrorCounter {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("NonceErrorCounter").finish()
+        f.debug_struct("NonceErrorCounter")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for NonceErrorCounter {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "NonceErrorCounter {{  }}",);
+        defmt::write!(fmt, "NonceErrorCounter {{ raw: {=u32:#010x} }}", self.0);
     }
 }
 
@@ -1727,6 +3204,7 @@ impl defmt::Format for NonceErrorCounter {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NonceOverflowCounter(u32);
 impl_boilerplate_for!(NonceOverflowCounter);
 
@@ -1763,18 +3241,22 @@ impl NonceOverflowCounter {
 
     /// ## Bit mask for the `OVRF_CNT` field.
     pub const OVRF_CNT_MASK: u32 = 0xffff_ffff << Self::OVRF_CNT_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !Self::OVRF_CNT_MASK;
 }
 
 impl ::core::fmt::Display for NonceOverflowCounter {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("NonceOverflowCounter").finish()
+        f.debug_struct("NonceOverflowCounter")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for NonceOverflowCounter {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "NonceOverflowCounter {{  }}",);
+        defmt::write!(fmt, "NonceOverflowCounter {{ raw: {=u32:#010x} }}", self.0);
     }
 }
 
@@ -1782,6 +3264,7 @@ impl defmt::Format for NonceOverflowCounter {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnalogMuxControl(u32);
 impl_boilerplate_for!(AnalogMuxControl);
 
@@ -1818,18 +3301,22 @@ impl AnalogMuxControl {
 
     /// ## Bit mask for the `DIODE_VDD_MUX_SEL` field.
     pub const DIODE_VDD_MUX_SEL_MASK: u32 = 0b111 << Self::DIODE_VDD_MUX_SEL_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !Self::DIODE_VDD_MUX_SEL_MASK;
 }
 
 impl ::core::fmt::Display for AnalogMuxControl {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("AnalogMuxControl").finish()
+        f.debug_struct("AnalogMuxControl")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for AnalogMuxControl {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "AnalogMuxControl {{  }}",);
+        defmt::write!(fmt, "AnalogMuxControl {{ raw: {=u32:#010x} }}", self.0);
     }
 }
 
@@ -1837,6 +3324,7 @@ impl defmt::Format for AnalogMuxControl {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IoDriverStrenghtConfiguration(u32);
 impl_boilerplate_for!(IoDriverStrenghtConfiguration);
 
@@ -1909,18 +3397,35 @@ impl IoDriverStrenghtConfiguration {
     pub const BO_DS_MASK: u32 = 0b1111 << Self::BO_DS_OFFSET;
     /// ## Bit mask for the `CO_DS` field.
     pub const CO_DS_MASK: u32 = 0b1111 << Self::CO_DS_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !(Self::RF_DS_MASK
+        | Self::D3RS_EN_MASK
+        | Self::D2RS_EN_MASK
+        | Self::D1RS_EN_MASK
+        | Self::D0RS_EN_MASK
+        | Self::RO_DS_MASK
+        | Self::CLKO_DS_MASK
+        | Self::NRSTO_DS_MASK
+        | Self::BO_DS_MASK
+        | Self::CO_DS_MASK);
 }
 
 impl ::core::fmt::Display for IoDriverStrenghtConfiguration {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("IoDriverStrenghtConfiguration").finish()
+        f.debug_struct("IoDriverStrenghtConfiguration")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for IoDriverStrenghtConfiguration {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "IoDriverStrenghtConfiguration {{  }}",);
+        defmt::write!(
+            fmt,
+            "IoDriverStrenghtConfiguration {{ raw: {=u32:#010x} }}",
+            self.0
+        );
     }
 }
 
@@ -1928,6 +3433,7 @@ impl defmt::Format for IoDriverStrenghtConfiguration {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TimeOut(u32);
 impl_boilerplate_for!(TimeOut);
 
@@ -1964,18 +3470,22 @@ impl TimeOut {
 
     /// ## Bit mask for the `TMOUT` field.
     pub const TMOUT_MASK: u32 = 0xffff << Self::TMOUT_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !Self::TMOUT_MASK;
 }
 
 impl ::core::fmt::Display for TimeOut {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("TimeOut").finish()
+        f.debug_struct("TimeOut")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for TimeOut {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "TimeOut {{  }}",);
+        defmt::write!(fmt, "TimeOut {{ raw: {=u32:#010x} }}", self.0);
     }
 }
 
@@ -1983,6 +3493,7 @@ impl defmt::Format for TimeOut {
 ///
 /// Used to set PLL1 frequency.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PLL1Parameter(u32);
 impl_boilerplate_for!(PLL1Parameter);
 
@@ -2039,6 +3550,37 @@ impl PLL1Parameter {
     pub const POSTDIV1_MASK: u32 = 0x7 << Self::POSTDIV1_OFFSET;
     /// ## Bit mask for the `POSTDIV2` field.
     pub const POSTDIV2_MASK: u32 = 0x7 << Self::POSTDIV2_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !(Self::LOCKED_MASK
+        | Self::PLLEN_MASK
+        | Self::FBDIV_MASK
+        | Self::REFDIV_MASK
+        | Self::POSTDIV1_MASK
+        | Self::POSTDIV2_MASK);
+
+    /// ## Build an enabled PLL1 Parameter from its dividers.
+    ///
+    /// Same as chaining `set_fbdiv`, `set_refdiv`, `set_postdiv1` and `set_postdiv2`
+    /// on an enabled PLL1 : the dividers are masked, use the `try_set_*` methods
+    /// to check their range. A 0 divider is kept, `frequency` then returns 0 Hz.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL1Parameter;
+    /// use fugit::HertzU32;
+    ///
+    /// const PLL1: PLL1Parameter = PLL1Parameter::from_dividers(0x64, 1, 1, 1);
+    /// assert!(PLL1.enabled());
+    /// assert_eq!(PLL1.frequency(HertzU32::MHz(25)), HertzU32::MHz(2500u32));
+    /// ```
+    pub const fn from_dividers(fbdiv: u16, refdiv: u8, postdiv1: u8, postdiv2: u8) -> Self {
+        Self(Self::PLLEN_MASK)
+            .set_fbdiv(fbdiv)
+            .set_refdiv(refdiv)
+            .set_postdiv1(postdiv1)
+            .set_postdiv2(postdiv2)
+    }
 
     /// ## Get the PLL1 locked state.
     ///
@@ -2130,6 +3672,27 @@ impl PLL1Parameter {
         self.0 |= ((fbdiv as u32) << Self::FBDIV_OFFSET) & Self::FBDIV_MASK;
         self
     }
+    /// ## Set the PLL1 FB Divider, checking its range.
+    ///
+    /// This returns an `Err(u16)` with the given value if it is greater than `0xFFF`,
+    /// instead of silently masking it like `set_fbdiv`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL1Parameter;
+    ///
+    /// let pll1 = PLL1Parameter::DEFAULT.try_set_fbdiv(0x0AAA);
+    /// assert_eq!(pll1.unwrap().fbdiv(), 0x0AAA);
+    /// let pll1 = PLL1Parameter::DEFAULT.try_set_fbdiv(0x1000);
+    /// assert_eq!(pll1, Err(0x1000));
+    /// ```
+    pub const fn try_set_fbdiv(self, fbdiv: u16) -> Result<Self, u16> {
+        if (fbdiv as u32) > (Self::FBDIV_MASK >> Self::FBDIV_OFFSET) {
+            return Err(fbdiv);
+        }
+        Ok(self.set_fbdiv(fbdiv))
+    }
 
     /// ## Get the PLL1 REF Divider.
     ///
@@ -2157,6 +3720,28 @@ impl PLL1Parameter {
         self.0 |= ((refdiv as u32) << Self::REFDIV_OFFSET) & Self::REFDIV_MASK;
         self
     }
+    /// ## Set the PLL1 REF Divider, checking its range.
+    ///
+    /// This returns an `Err(u8)` with the given value if it is 0 or greater than `0x3F`,
+    /// instead of silently masking it like `set_refdiv`. A null divider has no frequency.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL1Parameter;
+    ///
+    /// let pll1 = PLL1Parameter::DEFAULT.try_set_refdiv(0x2A);
+    /// assert_eq!(pll1.unwrap().refdiv(), 0x2A);
+    /// let pll1 = PLL1Parameter::DEFAULT.try_set_refdiv(0x40);
+    /// assert_eq!(pll1, Err(0x40));
+    /// assert_eq!(PLL1Parameter::DEFAULT.try_set_refdiv(0), Err(0));
+    /// ```
+    pub const fn try_set_refdiv(self, refdiv: u8) -> Result<Self, u8> {
+        if refdiv == 0 || (refdiv as u32) > (Self::REFDIV_MASK >> Self::REFDIV_OFFSET) {
+            return Err(refdiv);
+        }
+        Ok(self.set_refdiv(refdiv))
+    }
 
     /// ## Get the PLL1 POST Divider 1.
     ///
@@ -2184,6 +3769,28 @@ impl PLL1Parameter {
         self.0 |= ((postdiv1 as u32) << Self::POSTDIV1_OFFSET) & Self::POSTDIV1_MASK;
         self
     }
+    /// ## Set the PLL1 POST Divider 1, checking its range.
+    ///
+    /// This returns an `Err(u8)` with the given value if it is 0 or greater than `0x7`,
+    /// instead of silently masking it like `set_postdiv1`. A null divider has no frequency.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL1Parameter;
+    ///
+    /// let pll1 = PLL1Parameter::DEFAULT.try_set_postdiv1(0x07);
+    /// assert_eq!(pll1.unwrap().postdiv1(), 0x07);
+    /// let pll1 = PLL1Parameter::DEFAULT.try_set_postdiv1(0x08);
+    /// assert_eq!(pll1, Err(0x08));
+    /// assert_eq!(PLL1Parameter::DEFAULT.try_set_postdiv1(0), Err(0));
+    /// ```
+    pub const fn try_set_postdiv1(self, postdiv1: u8) -> Result<Self, u8> {
+        if postdiv1 == 0 || (postdiv1 as u32) > (Self::POSTDIV1_MASK >> Self::POSTDIV1_OFFSET) {
+            return Err(postdiv1);
+        }
+        Ok(self.set_postdiv1(postdiv1))
+    }
 
     /// ## Get the PLL1 POST Divider 2.
     ///
@@ -2211,10 +3818,55 @@ impl PLL1Parameter {
         self.0 |= ((postdiv2 as u32) << Self::POSTDIV2_OFFSET) & Self::POSTDIV2_MASK;
         self
     }
+    /// ## Set the PLL1 POST Divider 2, checking its range.
+    ///
+    /// This returns an `Err(u8)` with the given value if it is 0 or greater than `0x7`,
+    /// instead of silently masking it like `set_postdiv2`. A null divider has no frequency.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL1Parameter;
+    ///
+    /// let pll1 = PLL1Parameter::DEFAULT.try_set_postdiv2(0x07);
+    /// assert_eq!(pll1.unwrap().postdiv2(), 0x07);
+    /// let pll1 = PLL1Parameter::DEFAULT.try_set_postdiv2(0x08);
+    /// assert_eq!(pll1, Err(0x08));
+    /// assert_eq!(PLL1Parameter::DEFAULT.try_set_postdiv2(0), Err(0));
+    /// ```
+    pub const fn try_set_postdiv2(self, postdiv2: u8) -> Result<Self, u8> {
+        if postdiv2 == 0 || (postdiv2 as u32) > (Self::POSTDIV2_MASK >> Self::POSTDIV2_OFFSET) {
+            return Err(postdiv2);
+        }
+        Ok(self.set_postdiv2(postdiv2))
+    }
+
+    /// ## Get the PLL1 divider ratio.
+    ///
+    /// This returns an `(u16, u32)` with the multiply factor `fbdiv` and the divide factor
+    /// `refdiv * postdiv1 * postdiv2`, so the PLL1 Frequency is `clki_freq * fbdiv / divider`.
+    /// A null divider means the PLL1 Frequency can not be computed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL1Parameter;
+    ///
+    /// assert_eq!(PLL1Parameter::from_dividers(0x60, 1, 6, 1).divider_ratio(), (0x60, 6));
+    /// assert_eq!(PLL1Parameter::from_dividers(0x60, 0, 6, 1).divider_ratio(), (0x60, 0));
+    /// ```
+    pub const fn divider_ratio(&self) -> (u16, u32) {
+        (
+            self.fbdiv(),
+            (self.refdiv() as u32) * (self.postdiv1() as u32) * (self.postdiv2() as u32),
+        )
+    }
 
     /// ## Get the PLL1 Frequency.
     ///
     /// This returns an `HertzU32` with the PLL1 Frequency according to the clki_freq parameter.
+    /// A 0 `REFDIV`, `POSTDIV1` or `POSTDIV2` divider gives 0 Hz, and the result saturates
+    /// at `u32::MAX` Hz.
     ///
     /// ### Example
     ///
@@ -2224,18 +3876,27 @@ impl PLL1Parameter {
     ///
     /// let clki_freq = HertzU32::MHz(25);
     /// assert_eq!(PLL1Parameter::DEFAULT.frequency(clki_freq), HertzU32::MHz(2500u32));
+    /// let pll1 = PLL1Parameter::DEFAULT.set_postdiv2(0);
+    /// assert_eq!(pll1.frequency(clki_freq), HertzU32::from_raw(0));
     /// ```
     pub const fn frequency(&self, clki_freq: HertzU32) -> HertzU32 {
-        HertzU32::from_raw(
-            clki_freq.raw() * (self.fbdiv() as u32)
-                / ((self.refdiv() as u32) * (self.postdiv1() as u32) * (self.postdiv2() as u32)),
-        )
+        let div = (self.refdiv() as u64) * (self.postdiv1() as u64) * (self.postdiv2() as u64);
+        if div == 0 {
+            return HertzU32::from_raw(0);
+        }
+        let freq = (clki_freq.raw() as u64) * (self.fbdiv() as u64) / div;
+        if freq > u32::MAX as u64 {
+            HertzU32::from_raw(u32::MAX)
+        } else {
+            HertzU32::from_raw(freq as u32)
+        }
     }
 }
 
 impl ::core::fmt::Display for PLL1Parameter {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         f.debug_struct("PLL1Parameter")
+            .field("raw", &format_args!("{:#010x}", self.0))
             .field("locked", &self.locked())
             .field("enabled", &self.enabled())
             .field("fbdiv", &self.fbdiv())
@@ -2251,7 +3912,8 @@ impl defmt::Format for PLL1Parameter {
     fn format(&self, fmt: defmt::Formatter) {
         defmt::write!(
             fmt,
-            "PLL1Parameter {{ locked: {}, enabled: {}, fbdiv: {}, refdiv: {}, postdiv1: {}, postdiv2: {} }}",
+            "PLL1Parameter {{ raw: {=u32:#010x}, locked: {}, enabled: {}, fbdiv: {}, refdiv: {}, postdiv1: {}, postdiv2: {} }}",
+            self.0,
             self.locked(),
             self.enabled(),
             self.fbdiv(),
@@ -2266,6 +3928,7 @@ impl defmt::Format for PLL1Parameter {
 ///
 /// Used to set PLL2 frequency.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PLL2Parameter(u32);
 impl_boilerplate_for!(PLL2Parameter);
 
@@ -2322,6 +3985,37 @@ impl PLL2Parameter {
     pub const POSTDIV1_MASK: u32 = 0x7 << Self::POSTDIV1_OFFSET;
     /// ## Bit mask for the `POSTDIV2` field.
     pub const POSTDIV2_MASK: u32 = 0x7 << Self::POSTDIV2_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !(Self::LOCKED_MASK
+        | Self::PLLEN_MASK
+        | Self::FBDIV_MASK
+        | Self::REFDIV_MASK
+        | Self::POSTDIV1_MASK
+        | Self::POSTDIV2_MASK);
+
+    /// ## Build an enabled PLL2 Parameter from its dividers.
+    ///
+    /// Same as chaining `set_fbdiv`, `set_refdiv`, `set_postdiv1` and `set_postdiv2`
+    /// on an enabled PLL2 : the dividers are masked, use the `try_set_*` methods
+    /// to check their range. A 0 divider is kept, `frequency` then returns 0 Hz.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL2Parameter;
+    /// use fugit::HertzU32;
+    ///
+    /// const PLL2: PLL2Parameter = PLL2Parameter::from_dividers(0x68, 1, 1, 1);
+    /// assert!(PLL2.enabled());
+    /// assert_eq!(PLL2.frequency(HertzU32::MHz(25)), HertzU32::MHz(2600u32));
+    /// ```
+    pub const fn from_dividers(fbdiv: u16, refdiv: u8, postdiv1: u8, postdiv2: u8) -> Self {
+        Self(Self::PLLEN_MASK)
+            .set_fbdiv(fbdiv)
+            .set_refdiv(refdiv)
+            .set_postdiv1(postdiv1)
+            .set_postdiv2(postdiv2)
+    }
 
     /// ## Get the PLL2 locked state.
     ///
@@ -2413,6 +4107,27 @@ impl PLL2Parameter {
         self.0 |= ((fbdiv as u32) << Self::FBDIV_OFFSET) & Self::FBDIV_MASK;
         self
     }
+    /// ## Set the PLL2 FB Divider, checking its range.
+    ///
+    /// This returns an `Err(u16)` with the given value if it is greater than `0xFFF`,
+    /// instead of silently masking it like `set_fbdiv`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL2Parameter;
+    ///
+    /// let pll2 = PLL2Parameter::DEFAULT.try_set_fbdiv(0x0AAA);
+    /// assert_eq!(pll2.unwrap().fbdiv(), 0x0AAA);
+    /// let pll2 = PLL2Parameter::DEFAULT.try_set_fbdiv(0x1000);
+    /// assert_eq!(pll2, Err(0x1000));
+    /// ```
+    pub const fn try_set_fbdiv(self, fbdiv: u16) -> Result<Self, u16> {
+        if (fbdiv as u32) > (Self::FBDIV_MASK >> Self::FBDIV_OFFSET) {
+            return Err(fbdiv);
+        }
+        Ok(self.set_fbdiv(fbdiv))
+    }
 
     /// ## Get the PLL2 REF Divider.
     ///
@@ -2440,6 +4155,28 @@ impl PLL2Parameter {
         self.0 |= ((refdiv as u32) << Self::REFDIV_OFFSET) & Self::REFDIV_MASK;
         self
     }
+    /// ## Set the PLL2 REF Divider, checking its range.
+    ///
+    /// This returns an `Err(u8)` with the given value if it is 0 or greater than `0x3F`,
+    /// instead of silently masking it like `set_refdiv`. A null divider has no frequency.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL2Parameter;
+    ///
+    /// let pll2 = PLL2Parameter::DEFAULT.try_set_refdiv(0x2A);
+    /// assert_eq!(pll2.unwrap().refdiv(), 0x2A);
+    /// let pll2 = PLL2Parameter::DEFAULT.try_set_refdiv(0x40);
+    /// assert_eq!(pll2, Err(0x40));
+    /// assert_eq!(PLL2Parameter::DEFAULT.try_set_refdiv(0), Err(0));
+    /// ```
+    pub const fn try_set_refdiv(self, refdiv: u8) -> Result<Self, u8> {
+        if refdiv == 0 || (refdiv as u32) > (Self::REFDIV_MASK >> Self::REFDIV_OFFSET) {
+            return Err(refdiv);
+        }
+        Ok(self.set_refdiv(refdiv))
+    }
 
     /// ## Get the PLL2 POST Divider 1.
     ///
@@ -2467,6 +4204,28 @@ impl PLL2Parameter {
         self.0 |= ((postdiv1 as u32) << Self::POSTDIV1_OFFSET) & Self::POSTDIV1_MASK;
         self
     }
+    /// ## Set the PLL2 POST Divider 1, checking its range.
+    ///
+    /// This returns an `Err(u8)` with the given value if it is 0 or greater than `0x7`,
+    /// instead of silently masking it like `set_postdiv1`. A null divider has no frequency.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL2Parameter;
+    ///
+    /// let pll2 = PLL2Parameter::DEFAULT.try_set_postdiv1(0x07);
+    /// assert_eq!(pll2.unwrap().postdiv1(), 0x07);
+    /// let pll2 = PLL2Parameter::DEFAULT.try_set_postdiv1(0x08);
+    /// assert_eq!(pll2, Err(0x08));
+    /// assert_eq!(PLL2Parameter::DEFAULT.try_set_postdiv1(0), Err(0));
+    /// ```
+    pub const fn try_set_postdiv1(self, postdiv1: u8) -> Result<Self, u8> {
+        if postdiv1 == 0 || (postdiv1 as u32) > (Self::POSTDIV1_MASK >> Self::POSTDIV1_OFFSET) {
+            return Err(postdiv1);
+        }
+        Ok(self.set_postdiv1(postdiv1))
+    }
 
     /// ## Get the PLL2 POST Divider 2.
     ///
@@ -2494,10 +4253,55 @@ impl PLL2Parameter {
         self.0 |= ((postdiv2 as u32) << Self::POSTDIV2_OFFSET) & Self::POSTDIV2_MASK;
         self
     }
+    /// ## Set the PLL2 POST Divider 2, checking its range.
+    ///
+    /// This returns an `Err(u8)` with the given value if it is 0 or greater than `0x7`,
+    /// instead of silently masking it like `set_postdiv2`. A null divider has no frequency.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL2Parameter;
+    ///
+    /// let pll2 = PLL2Parameter::DEFAULT.try_set_postdiv2(0x07);
+    /// assert_eq!(pll2.unwrap().postdiv2(), 0x07);
+    /// let pll2 = PLL2Parameter::DEFAULT.try_set_postdiv2(0x08);
+    /// assert_eq!(pll2, Err(0x08));
+    /// assert_eq!(PLL2Parameter::DEFAULT.try_set_postdiv2(0), Err(0));
+    /// ```
+    pub const fn try_set_postdiv2(self, postdiv2: u8) -> Result<Self, u8> {
+        if postdiv2 == 0 || (postdiv2 as u32) > (Self::POSTDIV2_MASK >> Self::POSTDIV2_OFFSET) {
+            return Err(postdiv2);
+        }
+        Ok(self.set_postdiv2(postdiv2))
+    }
+
+    /// ## Get the PLL2 divider ratio.
+    ///
+    /// This returns an `(u16, u32)` with the multiply factor `fbdiv` and the divide factor
+    /// `refdiv * postdiv1 * postdiv2`, so the PLL2 Frequency is `clki_freq * fbdiv / divider`.
+    /// A null divider means the PLL2 Frequency can not be computed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL2Parameter;
+    ///
+    /// assert_eq!(PLL2Parameter::from_dividers(0x60, 1, 6, 1).divider_ratio(), (0x60, 6));
+    /// assert_eq!(PLL2Parameter::from_dividers(0x60, 0, 6, 1).divider_ratio(), (0x60, 0));
+    /// ```
+    pub const fn divider_ratio(&self) -> (u16, u32) {
+        (
+            self.fbdiv(),
+            (self.refdiv() as u32) * (self.postdiv1() as u32) * (self.postdiv2() as u32),
+        )
+    }
 
     /// ## Get the PLL2 Frequency.
     ///
     /// This returns an `HertzU32` with the PLL2 Frequency according to the clki_freq parameter.
+    /// A 0 `REFDIV`, `POSTDIV1` or `POSTDIV2` divider gives 0 Hz, and the result saturates
+    /// at `u32::MAX` Hz.
     ///
     /// ### Example
     ///
@@ -2507,18 +4311,27 @@ impl PLL2Parameter {
     ///
     /// let clki_freq = HertzU32::MHz(25);
     /// assert_eq!(PLL2Parameter::DEFAULT.frequency(clki_freq), HertzU32::MHz(2600u32));
+    /// let pll2 = PLL2Parameter::DEFAULT.set_postdiv2(0);
+    /// assert_eq!(pll2.frequency(clki_freq), HertzU32::from_raw(0));
     /// ```
     pub const fn frequency(&self, clki_freq: HertzU32) -> HertzU32 {
-        HertzU32::from_raw(
-            clki_freq.raw() * (self.fbdiv() as u32)
-                / ((self.refdiv() as u32) * (self.postdiv1() as u32) * (self.postdiv2() as u32)),
-        )
+        let div = (self.refdiv() as u64) * (self.postdiv1() as u64) * (self.postdiv2() as u64);
+        if div == 0 {
+            return HertzU32::from_raw(0);
+        }
+        let freq = (clki_freq.raw() as u64) * (self.fbdiv() as u64) / div;
+        if freq > u32::MAX as u64 {
+            HertzU32::from_raw(u32::MAX)
+        } else {
+            HertzU32::from_raw(freq as u32)
+        }
     }
 }
 
 impl ::core::fmt::Display for PLL2Parameter {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         f.debug_struct("PLL2Parameter")
+            .field("raw", &format_args!("{:#010x}", self.0))
             .field("locked", &self.locked())
             .field("enabled", &self.enabled())
             .field("fbdiv", &self.fbdiv())
@@ -2534,7 +4347,8 @@ impl defmt::Format for PLL2Parameter {
     fn format(&self, fmt: defmt::Formatter) {
         defmt::write!(
             fmt,
-            "PLL2Parameter {{ locked: {}, enabled: {}, fbdiv: {}, refdiv: {}, postdiv1: {}, postdiv2: {} }}",
+            "PLL2Parameter {{ raw: {=u32:#010x}, locked: {}, enabled: {}, fbdiv: {}, refdiv: {}, postdiv1: {}, postdiv2: {} }}",
+            self.0,
             self.locked(),
             self.enabled(),
             self.fbdiv(),
@@ -2549,6 +4363,7 @@ impl defmt::Format for PLL2Parameter {
 ///
 /// Used to set PLL3 frequency.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PLL3Parameter(u32);
 impl_boilerplate_for!(PLL3Parameter);
 
@@ -2605,6 +4420,37 @@ impl PLL3Parameter {
     pub const POSTDIV1_MASK: u32 = 0x7 << Self::POSTDIV1_OFFSET;
     /// ## Bit mask for the `POSTDIV2` field.
     pub const POSTDIV2_MASK: u32 = 0x7 << Self::POSTDIV2_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !(Self::LOCKED_MASK
+        | Self::PLLEN_MASK
+        | Self::FBDIV_MASK
+        | Self::REFDIV_MASK
+        | Self::POSTDIV1_MASK
+        | Self::POSTDIV2_MASK);
+
+    /// ## Build an enabled PLL3 Parameter from its dividers.
+    ///
+    /// Same as chaining `set_fbdiv`, `set_refdiv`, `set_postdiv1` and `set_postdiv2`
+    /// on an enabled PLL3 : the dividers are masked, use the `try_set_*` methods
+    /// to check their range. A 0 divider is kept, `frequency` then returns 0 Hz.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL3Parameter;
+    /// use fugit::HertzU32;
+    ///
+    /// const PLL3: PLL3Parameter = PLL3Parameter::from_dividers(0x70, 1, 1, 1);
+    /// assert!(PLL3.enabled());
+    /// assert_eq!(PLL3.frequency(HertzU32::MHz(25)), HertzU32::MHz(2800u32));
+    /// ```
+    pub const fn from_dividers(fbdiv: u16, refdiv: u8, postdiv1: u8, postdiv2: u8) -> Self {
+        Self(Self::PLLEN_MASK)
+            .set_fbdiv(fbdiv)
+            .set_refdiv(refdiv)
+            .set_postdiv1(postdiv1)
+            .set_postdiv2(postdiv2)
+    }
 
     /// ## Get the PLL3 locked state.
     ///
@@ -2696,6 +4542,27 @@ impl PLL3Parameter {
         self.0 |= ((fbdiv as u32) << Self::FBDIV_OFFSET) & Self::FBDIV_MASK;
         self
     }
+    /// ## Set the PLL3 FB Divider, checking its range.
+    ///
+    /// This returns an `Err(u16)` with the given value if it is greater than `0xFFF`,
+    /// instead of silently masking it like `set_fbdiv`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL3Parameter;
+    ///
+    /// let pll3 = PLL3Parameter::DEFAULT.try_set_fbdiv(0x0AAA);
+    /// assert_eq!(pll3.unwrap().fbdiv(), 0x0AAA);
+    /// let pll3 = PLL3Parameter::DEFAULT.try_set_fbdiv(0x1000);
+    /// assert_eq!(pll3, Err(0x1000));
+    /// ```
+    pub const fn try_set_fbdiv(self, fbdiv: u16) -> Result<Self, u16> {
+        if (fbdiv as u32) > (Self::FBDIV_MASK >> Self::FBDIV_OFFSET) {
+            return Err(fbdiv);
+        }
+        Ok(self.set_fbdiv(fbdiv))
+    }
 
     /// ## Get the PLL3 REF Divider.
     ///
@@ -2723,6 +4590,28 @@ impl PLL3Parameter {
         self.0 |= ((refdiv as u32) << Self::REFDIV_OFFSET) & Self::REFDIV_MASK;
         self
     }
+    /// ## Set the PLL3 REF Divider, checking its range.
+    ///
+    /// This returns an `Err(u8)` with the given value if it is 0 or greater than `0x3F`,
+    /// instead of silently masking it like `set_refdiv`. A null divider has no frequency.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL3Parameter;
+    ///
+    /// let pll3 = PLL3Parameter::DEFAULT.try_set_refdiv(0x2A);
+    /// assert_eq!(pll3.unwrap().refdiv(), 0x2A);
+    /// let pll3 = PLL3Parameter::DEFAULT.try_set_refdiv(0x40);
+    /// assert_eq!(pll3, Err(0x40));
+    /// assert_eq!(PLL3Parameter::DEFAULT.try_set_refdiv(0), Err(0));
+    /// ```
+    pub const fn try_set_refdiv(self, refdiv: u8) -> Result<Self, u8> {
+        if refdiv == 0 || (refdiv as u32) > (Self::REFDIV_MASK >> Self::REFDIV_OFFSET) {
+            return Err(refdiv);
+        }
+        Ok(self.set_refdiv(refdiv))
+    }
 
     /// ## Get the PLL3 POST Divider 1.
     ///
@@ -2750,6 +4639,28 @@ impl PLL3Parameter {
         self.0 |= ((postdiv1 as u32) << Self::POSTDIV1_OFFSET) & Self::POSTDIV1_MASK;
         self
     }
+    /// ## Set the PLL3 POST Divider 1, checking its range.
+    ///
+    /// This returns an `Err(u8)` with the given value if it is 0 or greater than `0x7`,
+    /// instead of silently masking it like `set_postdiv1`. A null divider has no frequency.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL3Parameter;
+    ///
+    /// let pll3 = PLL3Parameter::DEFAULT.try_set_postdiv1(0x07);
+    /// assert_eq!(pll3.unwrap().postdiv1(), 0x07);
+    /// let pll3 = PLL3Parameter::DEFAULT.try_set_postdiv1(0x08);
+    /// assert_eq!(pll3, Err(0x08));
+    /// assert_eq!(PLL3Parameter::DEFAULT.try_set_postdiv1(0), Err(0));
+    /// ```
+    pub const fn try_set_postdiv1(self, postdiv1: u8) -> Result<Self, u8> {
+        if postdiv1 == 0 || (postdiv1 as u32) > (Self::POSTDIV1_MASK >> Self::POSTDIV1_OFFSET) {
+            return Err(postdiv1);
+        }
+        Ok(self.set_postdiv1(postdiv1))
+    }
 
     /// ## Get the PLL3 POST Divider 2.
     ///
@@ -2777,10 +4688,55 @@ impl PLL3Parameter {
         self.0 |= ((postdiv2 as u32) << Self::POSTDIV2_OFFSET) & Self::POSTDIV2_MASK;
         self
     }
+    /// ## Set the PLL3 POST Divider 2, checking its range.
+    ///
+    /// This returns an `Err(u8)` with the given value if it is 0 or greater than `0x7`,
+    /// instead of silently masking it like `set_postdiv2`. A null divider has no frequency.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL3Parameter;
+    ///
+    /// let pll3 = PLL3Parameter::DEFAULT.try_set_postdiv2(0x07);
+    /// assert_eq!(pll3.unwrap().postdiv2(), 0x07);
+    /// let pll3 = PLL3Parameter::DEFAULT.try_set_postdiv2(0x08);
+    /// assert_eq!(pll3, Err(0x08));
+    /// assert_eq!(PLL3Parameter::DEFAULT.try_set_postdiv2(0), Err(0));
+    /// ```
+    pub const fn try_set_postdiv2(self, postdiv2: u8) -> Result<Self, u8> {
+        if postdiv2 == 0 || (postdiv2 as u32) > (Self::POSTDIV2_MASK >> Self::POSTDIV2_OFFSET) {
+            return Err(postdiv2);
+        }
+        Ok(self.set_postdiv2(postdiv2))
+    }
+
+    /// ## Get the PLL3 divider ratio.
+    ///
+    /// This returns an `(u16, u32)` with the multiply factor `fbdiv` and the divide factor
+    /// `refdiv * postdiv1 * postdiv2`, so the PLL3 Frequency is `clki_freq * fbdiv / divider`.
+    /// A null divider means the PLL3 Frequency can not be computed.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL3Parameter;
+    ///
+    /// assert_eq!(PLL3Parameter::from_dividers(0x60, 1, 6, 1).divider_ratio(), (0x60, 6));
+    /// assert_eq!(PLL3Parameter::from_dividers(0x60, 0, 6, 1).divider_ratio(), (0x60, 0));
+    /// ```
+    pub const fn divider_ratio(&self) -> (u16, u32) {
+        (
+            self.fbdiv(),
+            (self.refdiv() as u32) * (self.postdiv1() as u32) * (self.postdiv2() as u32),
+        )
+    }
 
     /// ## Get the PLL3 Frequency.
     ///
     /// This returns an `HertzU32` with the PLL3 Frequency according to the clki_freq parameter.
+    /// A 0 `REFDIV`, `POSTDIV1` or `POSTDIV2` divider gives 0 Hz, and the result saturates
+    /// at `u32::MAX` Hz.
     ///
     /// ### Example
     ///
@@ -2790,18 +4746,27 @@ impl PLL3Parameter {
     ///
     /// let clki_freq = HertzU32::MHz(25);
     /// assert_eq!(PLL3Parameter::DEFAULT.frequency(clki_freq), HertzU32::MHz(2800u32));
+    /// let pll3 = PLL3Parameter::DEFAULT.set_postdiv2(0);
+    /// assert_eq!(pll3.frequency(clki_freq), HertzU32::from_raw(0));
     /// ```
     pub const fn frequency(&self, clki_freq: HertzU32) -> HertzU32 {
-        HertzU32::from_raw(
-            clki_freq.raw() * (self.fbdiv() as u32)
-                / ((self.refdiv() as u32) * (self.postdiv1() as u32) * (self.postdiv2() as u32)),
-        )
+        let div = (self.refdiv() as u64) * (self.postdiv1() as u64) * (self.postdiv2() as u64);
+        if div == 0 {
+            return HertzU32::from_raw(0);
+        }
+        let freq = (clki_freq.raw() as u64) * (self.fbdiv() as u64) / div;
+        if freq > u32::MAX as u64 {
+            HertzU32::from_raw(u32::MAX)
+        } else {
+            HertzU32::from_raw(freq as u32)
+        }
     }
 }
 
 impl ::core::fmt::Display for PLL3Parameter {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         f.debug_struct("PLL3Parameter")
+            .field("raw", &format_args!("{:#010x}", self.0))
             .field("locked", &self.locked())
             .field("enabled", &self.enabled())
             .field("fbdiv", &self.fbdiv())
@@ -2817,7 +4782,8 @@ impl defmt::Format for PLL3Parameter {
     fn format(&self, fmt: defmt::Formatter) {
         defmt::write!(
             fmt,
-            "PLL3Parameter {{ locked: {}, enabled: {}, fbdiv: {}, refdiv: {}, postdiv1: {}, postdiv2: {} }}",
+            "PLL3Parameter {{ raw: {=u32:#010x}, locked: {}, enabled: {}, fbdiv: {}, refdiv: {}, postdiv1: {}, postdiv2: {} }}",
+            self.0,
             self.locked(),
             self.enabled(),
             self.fbdiv(),
@@ -2832,6 +4798,7 @@ impl defmt::Format for PLL3Parameter {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrderedClockMonitor(u32);
 impl_boilerplate_for!(OrderedClockMonitor);
 
@@ -2876,18 +4843,168 @@ impl OrderedClockMonitor {
     pub const CLK_SEL_MASK: u32 = 0b1111 << Self::CLK_SEL_OFFSET;
     /// ## Bit mask for the `CLK_COUNT` field.
     pub const CLK_COUNT_MASK: u32 = 0xffff << Self::CLK_COUNT_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !(Self::START_MASK | Self::CLK_SEL_MASK | Self::CLK_COUNT_MASK);
+
+    /// ## Get the measurement started state.
+    ///
+    /// This returns an `bool` with the measurement started state.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::OrderedClockMonitor;
+    ///
+    /// let ocm: OrderedClockMonitor = OrderedClockMonitor::DEFAULT;
+    /// assert!(!ocm.started());
+    /// let ocm: OrderedClockMonitor = ocm.start(5);
+    /// assert!(ocm.started());
+    /// assert_eq!(ocm.clk_sel(), 5);
+    /// let ocm: OrderedClockMonitor = ocm.stop();
+    /// assert!(!ocm.started());
+    /// ```
+    pub const fn started(&self) -> bool {
+        self.0 & Self::START_MASK == Self::START_MASK
+    }
+    /// ## Start the measurement of the `clk_sel` clock.
+    ///
+    /// Set the `START` bit and the `CLK_SEL` field, `clk_sel` being masked to 4 bits
+    /// so it can not spill into the other fields.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::OrderedClockMonitor;
+    ///
+    /// let ocm: OrderedClockMonitor = OrderedClockMonitor::from(0x0000_1234).start(5);
+    /// assert_eq!(ocm, 0x8500_1234);
+    /// assert_eq!(ocm.clk_sel(), 5);
+    /// assert_eq!(ocm.count(), 0x1234);
+    /// let ocm: OrderedClockMonitor = OrderedClockMonitor::DEFAULT.start(0x15);
+    /// assert_eq!(ocm, 0x8500_0000);
+    /// ```
+    #[must_use = "start returns a modified OrderedClockMonitor"]
+    pub const fn start(mut self, clk_sel: u8) -> Self {
+        self = self.set_clk_sel(clk_sel);
+        self.0 |= Self::START_MASK;
+        self
+    }
+    /// ## Stop the measurement.
+    #[must_use = "stop returns a modified OrderedClockMonitor"]
+    pub const fn stop(mut self) -> Self {
+        self.0 &= !Self::START_MASK;
+        self
+    }
+
+    /// ## Get the measured clock selection.
+    ///
+    /// This returns an `u8` with the measured clock index.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::OrderedClockMonitor;
+    ///
+    /// let ocm: OrderedClockMonitor = OrderedClockMonitor::DEFAULT;
+    /// assert_eq!(ocm.clk_sel(), 0);
+    /// let ocm: OrderedClockMonitor = ocm.set_clk_sel(0xA);
+    /// assert_eq!(ocm.clk_sel(), 0xA);
+    /// let ocm: OrderedClockMonitor = ocm.set_clk_sel(0xF5);
+    /// assert_eq!(ocm.clk_sel(), 0x5);
+    /// ```
+    pub const fn clk_sel(&self) -> u8 {
+        ((self.0 & Self::CLK_SEL_MASK) >> Self::CLK_SEL_OFFSET) as u8
+    }
+    /// ## Set the measured clock selection.
+    #[must_use = "set_clk_sel returns a modified OrderedClockMonitor"]
+    pub const fn set_clk_sel(mut self, clk_sel: u8) -> Self {
+        self.0 &= !Self::CLK_SEL_MASK;
+        self.0 |= ((clk_sel as u32) << Self::CLK_SEL_OFFSET) & Self::CLK_SEL_MASK;
+        self
+    }
+    /// ## Set the measured clock selection, checking its range.
+    ///
+    /// This returns an `Err(u8)` with the given value if it is greater than `15`,
+    /// instead of silently masking it like `set_clk_sel`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::OrderedClockMonitor;
+    ///
+    /// let ocm = OrderedClockMonitor::DEFAULT.try_set_clk_sel(15);
+    /// assert_eq!(ocm.unwrap().clk_sel(), 15);
+    /// let ocm = OrderedClockMonitor::DEFAULT.try_set_clk_sel(16);
+    /// assert_eq!(ocm, Err(16));
+    /// ```
+    pub const fn try_set_clk_sel(self, clk_sel: u8) -> Result<Self, u8> {
+        if (clk_sel as u32) > (Self::CLK_SEL_MASK >> Self::CLK_SEL_OFFSET) {
+            return Err(clk_sel);
+        }
+        Ok(self.set_clk_sel(clk_sel))
+    }
+
+    /// ## Get the clock count.
+    ///
+    /// This returns an `u16` with the number of clock cycles counted during the measurement.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::OrderedClockMonitor;
+    ///
+    /// let ocm: OrderedClockMonitor = OrderedClockMonitor::from(0x8500_1234);
+    /// assert_eq!(ocm.count(), 0x1234);
+    /// ```
+    pub const fn count(&self) -> u16 {
+        ((self.0 & Self::CLK_COUNT_MASK) >> Self::CLK_COUNT_OFFSET) as u16
+    }
+
+    /// ## Get the measured Frequency.
+    ///
+    /// This returns an `HertzU32` with the measured clock frequency, the clock count
+    /// divided by the sample `window` during which the clock was counted.
+    /// A null `window` returns 0 Hz.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::OrderedClockMonitor;
+    /// use fugit::{HertzU32, MicrosDurationU32};
+    ///
+    /// let ocm: OrderedClockMonitor = OrderedClockMonitor::from(0x8500_9C40);
+    /// assert_eq!(ocm.frequency(MicrosDurationU32::micros(100)), HertzU32::MHz(400));
+    /// ```
+    pub const fn frequency(&self, window: fugit::MicrosDurationU32) -> HertzU32 {
+        if window.ticks() == 0 {
+            return HertzU32::from_raw(0);
+        }
+        HertzU32::from_raw((self.count() as u64 * 1_000_000 / window.ticks() as u64) as u32)
+    }
 }
 
 impl ::core::fmt::Display for OrderedClockMonitor {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("OrderedClockMonitor").finish()
+        f.debug_struct("OrderedClockMonitor")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .field("started", &self.started())
+            .field("clk_sel", &self.clk_sel())
+            .field("count", &self.count())
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for OrderedClockMonitor {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "OrderedClockMonitor {{  }}",);
+        defmt::write!(
+            fmt,
+            "OrderedClockMonitor {{ raw: {=u32:#010x}, started: {}, clk_sel: {}, count: {} }}",
+            self.0,
+            self.started(),
+            self.clk_sel(),
+            self.count(),
+        );
     }
 }
 
@@ -2895,6 +5012,7 @@ impl defmt::Format for OrderedClockMonitor {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PLL0Divider(u32);
 impl_boilerplate_for!(PLL0Divider);
 
@@ -2943,18 +5061,66 @@ impl PLL0Divider {
     pub const PLLDIV1_MASK: u32 = 0b1111 << Self::PLLDIV1_OFFSET;
     /// ## Bit mask for the `PLLDIV0` field.
     pub const PLLDIV0_MASK: u32 = 0b1111 << Self::PLLDIV0_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 =
+        !(Self::PLLDIV3_MASK | Self::PLLDIV2_MASK | Self::PLLDIV1_MASK | Self::PLLDIV0_MASK);
+
+    /// ## Get a PLL0 Divider.
+    ///
+    /// This returns an `u8` with the PLLDIV`div` value, `div` being in `0..=3`.
+    ///
+    /// The frequency of the PLL0 divider output `div` is the PLL0 frequency
+    /// divided by `plldiv(div) + 1`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL0Divider;
+    ///
+    /// let pll0_div: PLL0Divider = PLL0Divider::DEFAULT;
+    /// assert_eq!(pll0_div.plldiv(0), 7);
+    /// assert_eq!(pll0_div.plldiv(1), 6);
+    /// assert_eq!(pll0_div.plldiv(2), 4);
+    /// assert_eq!(pll0_div.plldiv(3), 3);
+    /// ```
+    pub const fn plldiv(&self, div: u8) -> u8 {
+        ((self.0 >> ((div & 0b11) * 8)) & 0b1111) as u8
+    }
+    /// ## Set a PLL0 Divider.
+    ///
+    /// Set the PLLDIV`div` value, `div` being in `0..=3`, `plldiv` is masked to 4 bits.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL0Divider;
+    ///
+    /// let pll0_div: PLL0Divider = PLL0Divider::from(0).set_plldiv(0, 0x0F).set_plldiv(2, 0x15);
+    /// assert_eq!(pll0_div.plldiv(0), 0x0F);
+    /// assert_eq!(pll0_div.plldiv(2), 0x05);
+    /// assert_eq!(pll0_div, 0x0005_000F);
+    /// ```
+    #[must_use = "set_plldiv returns a modified PLL0Divider"]
+    pub const fn set_plldiv(mut self, div: u8, plldiv: u8) -> Self {
+        let offset = (div & 0b11) * 8;
+        self.0 &= !(0b1111 << offset);
+        self.0 |= ((plldiv & 0b1111) as u32) << offset;
+        self
+    }
 }
 
 impl ::core::fmt::Display for PLL0Divider {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("PLL0Divider").finish()
+        f.debug_struct("PLL0Divider")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for PLL0Divider {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "PLL0Divider {{  }}",);
+        defmt::write!(fmt, "PLL0Divider {{ raw: {=u32:#010x} }}", self.0);
     }
 }
 
@@ -2962,6 +5128,7 @@ impl defmt::Format for PLL0Divider {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PLL1Divider(u32);
 impl_boilerplate_for!(PLL1Divider);
 
@@ -3010,18 +5177,66 @@ impl PLL1Divider {
     pub const PLLDIV1_MASK: u32 = 0b1111 << Self::PLLDIV1_OFFSET;
     /// ## Bit mask for the `PLLDIV0` field.
     pub const PLLDIV0_MASK: u32 = 0b1111 << Self::PLLDIV0_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 =
+        !(Self::PLLDIV3_MASK | Self::PLLDIV2_MASK | Self::PLLDIV1_MASK | Self::PLLDIV0_MASK);
+
+    /// ## Get a PLL1 Divider.
+    ///
+    /// This returns an `u8` with the PLLDIV`div` value, `div` being in `0..=3`.
+    ///
+    /// The frequency of the PLL1 divider output `div` is the PLL1 frequency
+    /// divided by `plldiv(div) + 1`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL1Divider;
+    ///
+    /// let pll1_div: PLL1Divider = PLL1Divider::DEFAULT;
+    /// assert_eq!(pll1_div.plldiv(0), 6);
+    /// assert_eq!(pll1_div.plldiv(1), 5);
+    /// assert_eq!(pll1_div.plldiv(2), 4);
+    /// assert_eq!(pll1_div.plldiv(3), 3);
+    /// ```
+    pub const fn plldiv(&self, div: u8) -> u8 {
+        ((self.0 >> ((div & 0b11) * 8)) & 0b1111) as u8
+    }
+    /// ## Set a PLL1 Divider.
+    ///
+    /// Set the PLLDIV`div` value, `div` being in `0..=3`, `plldiv` is masked to 4 bits.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL1Divider;
+    ///
+    /// let pll1_div: PLL1Divider = PLL1Divider::from(0).set_plldiv(0, 0x0F).set_plldiv(2, 0x15);
+    /// assert_eq!(pll1_div.plldiv(0), 0x0F);
+    /// assert_eq!(pll1_div.plldiv(2), 0x05);
+    /// assert_eq!(pll1_div, 0x0005_000F);
+    /// ```
+    #[must_use = "set_plldiv returns a modified PLL1Divider"]
+    pub const fn set_plldiv(mut self, div: u8, plldiv: u8) -> Self {
+        let offset = (div & 0b11) * 8;
+        self.0 &= !(0b1111 << offset);
+        self.0 |= ((plldiv & 0b1111) as u32) << offset;
+        self
+    }
 }
 
 impl ::core::fmt::Display for PLL1Divider {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("PLL1Divider").finish()
+        f.debug_struct("PLL1Divider")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for PLL1Divider {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "PLL1Divider {{  }}",);
+        defmt::write!(fmt, "PLL1Divider {{ raw: {=u32:#010x} }}", self.0);
     }
 }
 
@@ -3029,6 +5244,7 @@ impl defmt::Format for PLL1Divider {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PLL2Divider(u32);
 impl_boilerplate_for!(PLL2Divider);
 
@@ -3077,18 +5293,66 @@ impl PLL2Divider {
     pub const PLLDIV1_MASK: u32 = 0b1111 << Self::PLLDIV1_OFFSET;
     /// ## Bit mask for the `PLLDIV0` field.
     pub const PLLDIV0_MASK: u32 = 0b1111 << Self::PLLDIV0_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 =
+        !(Self::PLLDIV3_MASK | Self::PLLDIV2_MASK | Self::PLLDIV1_MASK | Self::PLLDIV0_MASK);
+
+    /// ## Get a PLL2 Divider.
+    ///
+    /// This returns an `u8` with the PLLDIV`div` value, `div` being in `0..=3`.
+    ///
+    /// The frequency of the PLL2 divider output `div` is the PLL2 frequency
+    /// divided by `plldiv(div) + 1`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL2Divider;
+    ///
+    /// let pll2_div: PLL2Divider = PLL2Divider::DEFAULT;
+    /// assert_eq!(pll2_div.plldiv(0), 6);
+    /// assert_eq!(pll2_div.plldiv(1), 5);
+    /// assert_eq!(pll2_div.plldiv(2), 4);
+    /// assert_eq!(pll2_div.plldiv(3), 3);
+    /// ```
+    pub const fn plldiv(&self, div: u8) -> u8 {
+        ((self.0 >> ((div & 0b11) * 8)) & 0b1111) as u8
+    }
+    /// ## Set a PLL2 Divider.
+    ///
+    /// Set the PLLDIV`div` value, `div` being in `0..=3`, `plldiv` is masked to 4 bits.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL2Divider;
+    ///
+    /// let pll2_div: PLL2Divider = PLL2Divider::from(0).set_plldiv(0, 0x0F).set_plldiv(2, 0x15);
+    /// assert_eq!(pll2_div.plldiv(0), 0x0F);
+    /// assert_eq!(pll2_div.plldiv(2), 0x05);
+    /// assert_eq!(pll2_div, 0x0005_000F);
+    /// ```
+    #[must_use = "set_plldiv returns a modified PLL2Divider"]
+    pub const fn set_plldiv(mut self, div: u8, plldiv: u8) -> Self {
+        let offset = (div & 0b11) * 8;
+        self.0 &= !(0b1111 << offset);
+        self.0 |= ((plldiv & 0b1111) as u32) << offset;
+        self
+    }
 }
 
 impl ::core::fmt::Display for PLL2Divider {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("PLL2Divider").finish()
+        f.debug_struct("PLL2Divider")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for PLL2Divider {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "PLL2Divider {{  }}",);
+        defmt::write!(fmt, "PLL2Divider {{ raw: {=u32:#010x} }}", self.0);
     }
 }
 
@@ -3096,6 +5360,7 @@ impl defmt::Format for PLL2Divider {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PLL3Divider(u32);
 impl_boilerplate_for!(PLL3Divider);
 
@@ -3144,23 +5409,72 @@ impl PLL3Divider {
     pub const PLLDIV1_MASK: u32 = 0b1111 << Self::PLLDIV1_OFFSET;
     /// ## Bit mask for the `PLLDIV0` field.
     pub const PLLDIV0_MASK: u32 = 0b1111 << Self::PLLDIV0_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 =
+        !(Self::PLLDIV3_MASK | Self::PLLDIV2_MASK | Self::PLLDIV1_MASK | Self::PLLDIV0_MASK);
+
+    /// ## Get a PLL3 Divider.
+    ///
+    /// This returns an `u8` with the PLLDIV`div` value, `div` being in `0..=3`.
+    ///
+    /// The frequency of the PLL3 divider output `div` is the PLL3 frequency
+    /// divided by `plldiv(div) + 1`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL3Divider;
+    ///
+    /// let pll3_div: PLL3Divider = PLL3Divider::DEFAULT;
+    /// assert_eq!(pll3_div.plldiv(0), 6);
+    /// assert_eq!(pll3_div.plldiv(1), 5);
+    /// assert_eq!(pll3_div.plldiv(2), 4);
+    /// assert_eq!(pll3_div.plldiv(3), 3);
+    /// ```
+    pub const fn plldiv(&self, div: u8) -> u8 {
+        ((self.0 >> ((div & 0b11) * 8)) & 0b1111) as u8
+    }
+    /// ## Set a PLL3 Divider.
+    ///
+    /// Set the PLLDIV`div` value, `div` being in `0..=3`, `plldiv` is masked to 4 bits.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::PLL3Divider;
+    ///
+    /// let pll3_div: PLL3Divider = PLL3Divider::from(0).set_plldiv(0, 0x0F).set_plldiv(2, 0x15);
+    /// assert_eq!(pll3_div.plldiv(0), 0x0F);
+    /// assert_eq!(pll3_div.plldiv(2), 0x05);
+    /// assert_eq!(pll3_div, 0x0005_000F);
+    /// ```
+    #[must_use = "set_plldiv returns a modified PLL3Divider"]
+    pub const fn set_plldiv(mut self, div: u8, plldiv: u8) -> Self {
+        let offset = (div & 0b11) * 8;
+        self.0 &= !(0b1111 << offset);
+        self.0 |= ((plldiv & 0b1111) as u32) << offset;
+        self
+    }
 }
 
 impl ::core::fmt::Display for PLL3Divider {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("PLL3Divider").finish()
+        f.debug_struct("PLL3Divider")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for PLL3Divider {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "PLL3Divider {{  }}",);
+        defmt::write!(fmt, "PLL3Divider {{ raw: {=u32:#010x} }}", self.0);
     }
 }
 
 /// # Clock Order Control 0 register
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClockOrderControl0(u32);
 impl_boilerplate_for!(ClockOrderControl0);
 
@@ -3195,8 +5509,16 @@ impl ClockOrderControl0 {
     /// ## Bit length for a `CLKN_SEL` field.
     pub const CLKN_SEL_LENGTH: u8 = 4;
 
+    /// ## Global index of the first clock of this register.
+    ///
+    /// The 16 clocks are split in 2 registers : clocks 0 to 7 in `ClockOrderControl0`
+    /// and clocks 8 to 15 in `ClockOrderControl1`.
+    pub const FIRST_CLOCK: u8 = 0;
+
     /// ## Bit mask for a `CLKN_SEL` field.
     pub const CLKN_SEL_MASK: u32 = 0xF;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = 0;
 
     /// ## Get the clock select.
     ///
@@ -3236,11 +5558,94 @@ impl ClockOrderControl0 {
         }
         self
     }
+    /// ## Set the clock select, checking the clock index.
+    ///
+    /// This returns an `Err(u8)` with the clock index if it is out of range (greater than 7),
+    /// instead of silently ignoring it like `set_clock_select`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::{specifier::ClockSelect, register::ClockOrderControl0};
+    ///
+    /// let clk_ord_ctrl = ClockOrderControl0::DEFAULT.try_set_clock_select(1, ClockSelect::Default);
+    /// assert_eq!(clk_ord_ctrl.unwrap().clock_select(1), Ok(ClockSelect::Default));
+    /// let clk_ord_ctrl = ClockOrderControl0::DEFAULT.try_set_clock_select(9, ClockSelect::Default);
+    /// assert_eq!(clk_ord_ctrl, Err(9));
+    /// ```
+    pub const fn try_set_clock_select(
+        self,
+        clock: u8,
+        clock_select: ClockSelect,
+    ) -> Result<Self, u8> {
+        if clock > 7 {
+            return Err(clock);
+        }
+        Ok(self.set_clock_select(clock, clock_select))
+    }
+
+    /// ## Get the global index of a clock.
+    ///
+    /// This returns an `u8` with the index of the `local` clock of this register among
+    /// the 16 clocks, ie `local + ClockOrderControl0::FIRST_CLOCK`, or an `Err(u8)` with `local` if
+    /// it is out of range (greater than 7).
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::ClockOrderControl0;
+    ///
+    /// assert_eq!(ClockOrderControl0::DEFAULT.global_index(3), Ok(3));
+    /// assert_eq!(ClockOrderControl0::DEFAULT.global_index(8), Err(8));
+    /// ```
+    pub const fn global_index(&self, local: u8) -> Result<u8, u8> {
+        if local > 7 {
+            return Err(local);
+        }
+        Ok(local + Self::FIRST_CLOCK)
+    }
+
+    /// ## Get the raw clock select bits.
+    ///
+    /// `clock` must be in `0..=7`.
+    pub(crate) const fn clock_select_bits(&self, clock: u8) -> u8 {
+        ((self.0 >> (clock * Self::CLKN_SEL_LENGTH)) & Self::CLKN_SEL_MASK) as u8
+    }
+
+    /// ## Check the clock order of both ClockOrderControl registers.
+    ///
+    /// This returns `true` if the 16 clock selects of `self` and `ctrl1` form a permutation
+    /// of the 16 clocks, each one being selected once and only once, like the reset order
+    /// `ClockOrderControl0::RESET` and `ClockOrderControl1::RESET` do.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::{ClockOrderControl0, ClockOrderControl1};
+    ///
+    /// let ctrl0 = ClockOrderControl0::DEFAULT;
+    /// let ctrl1 = ClockOrderControl1::DEFAULT;
+    /// assert!(ctrl0.is_valid_permutation(&ctrl1));
+    /// // clock 0x0 selected twice, clock 0x2 never
+    /// let ctrl1 = ClockOrderControl1::from(0xFB73_EA60);
+    /// assert!(!ctrl0.is_valid_permutation(&ctrl1));
+    /// ```
+    pub const fn is_valid_permutation(&self, ctrl1: &ClockOrderControl1) -> bool {
+        let mut seen: u16 = 0;
+        let mut clock = 0;
+        while clock < 8 {
+            seen |= 1 << self.clock_select_bits(clock);
+            seen |= 1 << ctrl1.clock_select_bits(clock);
+            clock += 1;
+        }
+        seen == 0xFFFF
+    }
 }
 
 impl ::core::fmt::Display for ClockOrderControl0 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         f.debug_struct("ClockOrderControl0")
+            .field("raw", &format_args!("{:#010x}", self.0))
             .field("clock0_select", &self.clock_select(0))
             .field("clock1_select", &self.clock_select(1))
             .field("clock2_select", &self.clock_select(2))
@@ -3258,7 +5663,8 @@ impl defmt::Format for ClockOrderControl0 {
     fn format(&self, fmt: defmt::Formatter) {
         defmt::write!(
             fmt,
-            "ClockOrderControl0 {{ clock0_select: {}, clock1_select: {}, clock2_select: {}, clock3_select: {}, clock4_select: {}, clock5_select: {}, clock6_select: {}, clock7_select: {} }}",
+            "ClockOrderControl0 {{ raw: {=u32:#010x}, clock0_select: {}, clock1_select: {}, clock2_select: {}, clock3_select: {}, clock4_select: {}, clock5_select: {}, clock6_select: {}, clock7_select: {} }}",
+            self.0,
             self.clock_select(0),
             self.clock_select(1),
             self.clock_select(2),
@@ -3272,7 +5678,8 @@ impl defmt::Format for ClockOrderControl0 {
 }
 
 /// # Clock Order Control 1 register
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClockOrderControl1(u32);
 impl_boilerplate_for!(ClockOrderControl1);
 
@@ -3307,8 +5714,16 @@ impl ClockOrderControl1 {
     /// ## Bit length for a `CLKN_SEL` field.
     pub const CLKN_SEL_LENGTH: u8 = 4;
 
+    /// ## Global index of the first clock of this register.
+    ///
+    /// The 16 clocks are split in 2 registers : clocks 0 to 7 in `ClockOrderControl0`
+    /// and clocks 8 to 15 in `ClockOrderControl1`.
+    pub const FIRST_CLOCK: u8 = 8;
+
     /// ## Bit mask for a `CLKN_SEL` field.
     pub const CLKN_SEL_MASK: u32 = 0xF;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = 0;
 
     /// ## Get the clock select.
     ///
@@ -3349,11 +5764,65 @@ impl ClockOrderControl1 {
         }
         self
     }
+    /// ## Set the clock select, checking the clock index.
+    ///
+    /// This returns an `Err(u8)` with the clock index if it is out of range (greater than 7),
+    /// instead of silently ignoring it like `set_clock_select`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::{specifier::ClockSelect, register::ClockOrderControl1};
+    ///
+    /// let clk_ord_ctrl = ClockOrderControl1::DEFAULT.try_set_clock_select(1, ClockSelect::Default);
+    /// assert_eq!(clk_ord_ctrl.unwrap().clock_select(1), Ok(ClockSelect::Default));
+    /// let clk_ord_ctrl = ClockOrderControl1::DEFAULT.try_set_clock_select(9, ClockSelect::Default);
+    /// assert_eq!(clk_ord_ctrl, Err(9));
+    /// ```
+    pub const fn try_set_clock_select(
+        self,
+        clock: u8,
+        clock_select: ClockSelect,
+    ) -> Result<Self, u8> {
+        if clock > 7 {
+            return Err(clock);
+        }
+        Ok(self.set_clock_select(clock, clock_select))
+    }
+
+    /// ## Get the global index of a clock.
+    ///
+    /// This returns an `u8` with the index of the `local` clock of this register among
+    /// the 16 clocks, ie `local + ClockOrderControl1::FIRST_CLOCK`, or an `Err(u8)` with `local` if
+    /// it is out of range (greater than 7).
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::ClockOrderControl1;
+    ///
+    /// assert_eq!(ClockOrderControl1::DEFAULT.global_index(3), Ok(11));
+    /// assert_eq!(ClockOrderControl1::DEFAULT.global_index(8), Err(8));
+    /// ```
+    pub const fn global_index(&self, local: u8) -> Result<u8, u8> {
+        if local > 7 {
+            return Err(local);
+        }
+        Ok(local + Self::FIRST_CLOCK)
+    }
+
+    /// ## Get the raw clock select bits.
+    ///
+    /// `clock` must be in `0..=7`.
+    pub(crate) const fn clock_select_bits(&self, clock: u8) -> u8 {
+        ((self.0 >> (clock * Self::CLKN_SEL_LENGTH)) & Self::CLKN_SEL_MASK) as u8
+    }
 }
 
 impl ::core::fmt::Display for ClockOrderControl1 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
         f.debug_struct("ClockOrderControl1")
+            .field("raw", &format_args!("{:#010x}", self.0))
             .field("clock8_select", &self.clock_select(0))
             .field("clock9_select", &self.clock_select(1))
             .field("clock10_select", &self.clock_select(2))
@@ -3371,7 +5840,8 @@ impl defmt::Format for ClockOrderControl1 {
     fn format(&self, fmt: defmt::Formatter) {
         defmt::write!(
             fmt,
-            "ClockOrderControl1 {{ clock8_select: {}, clock9_select: {}, clock10_select: {}, clock11_select: {}, clock12_select: {}, clock13_select: {}, clock14_select: {}, clock15_select: {} }}",
+            "ClockOrderControl1 {{ raw: {=u32:#010x}, clock8_select: {}, clock9_select: {}, clock10_select: {}, clock11_select: {}, clock12_select: {}, clock13_select: {}, clock14_select: {}, clock15_select: {} }}",
+            self.0,
             self.clock_select(0),
             self.clock_select(1),
             self.clock_select(2),
@@ -3388,6 +5858,7 @@ impl defmt::Format for ClockOrderControl1 {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ClockOrderStatus(u32);
 impl_boilerplate_for!(ClockOrderStatus);
 
@@ -3424,18 +5895,22 @@ impl ClockOrderStatus {
 
     /// ## Bit mask for the `CLOK_ORDER_STATUS` field.
     pub const CLOK_ORDER_STATUS_MASK: u32 = 0xffff_ffff << Self::CLOK_ORDER_STATUS_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !Self::CLOK_ORDER_STATUS_MASK;
 }
 
 impl ::core::fmt::Display for ClockOrderStatus {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("ClockOrderStatus").finish()
+        f.debug_struct("ClockOrderStatus")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for ClockOrderStatus {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "ClockOrderStatus {{  }}",);
+        defmt::write!(fmt, "ClockOrderStatus {{ raw: {=u32:#010x} }}", self.0);
     }
 }
 
@@ -3443,6 +5918,7 @@ impl defmt::Format for ClockOrderStatus {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FrequencySweepControl1(u32);
 impl_boilerplate_for!(FrequencySweepControl1);
 
@@ -3479,18 +5955,45 @@ impl FrequencySweepControl1 {
 
     /// ## Bit mask for the `SWEEP_STATE` field.
     pub const SWEEP_STATE_MASK: u32 = 0b111 << Self::SWEEP_STATE_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !Self::SWEEP_STATE_MASK;
+
+    /// ## Get the Sweep State.
+    ///
+    /// This returns an `u8` with the 3-bits Sweep State reported by the chip,
+    /// the encoding of each state is not documented.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::FrequencySweepControl1;
+    ///
+    /// assert_eq!(FrequencySweepControl1::DEFAULT.sweep_state(), 0);
+    /// assert_eq!(FrequencySweepControl1::from(0x0500_0070).sweep_state(), 5);
+    /// ```
+    pub const fn sweep_state(&self) -> u8 {
+        ((self.0 & Self::SWEEP_STATE_MASK) >> Self::SWEEP_STATE_OFFSET) as u8
+    }
 }
 
 impl ::core::fmt::Display for FrequencySweepControl1 {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("FrequencySweepControl1").finish()
+        f.debug_struct("FrequencySweepControl1")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .field("sweep_state", &self.sweep_state())
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for FrequencySweepControl1 {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "FrequencySweepControl1 {{  }}",);
+        defmt::write!(
+            fmt,
+            "FrequencySweepControl1 {{ raw: {=u32:#010x}, sweep_state: {} }}",
+            self.0,
+            self.sweep_state(),
+        );
     }
 }
 
@@ -3498,6 +6001,7 @@ impl defmt::Format for FrequencySweepControl1 {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GoldenNonceForSweepReturn(u32);
 impl_boilerplate_for!(GoldenNonceForSweepReturn);
 
@@ -3534,18 +6038,46 @@ impl GoldenNonceForSweepReturn {
 
     /// ## Bit mask for the `GNOSWR` field.
     pub const GNOSWR_MASK: u32 = 0xffff_ffff << Self::GNOSWR_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !Self::GNOSWR_MASK;
+
+    /// ## Get the Golden Nonce.
+    ///
+    /// This returns an `u32` with the golden nonce returned by the last frequency sweep.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::GoldenNonceForSweepReturn;
+    ///
+    /// assert_eq!(GoldenNonceForSweepReturn::DEFAULT.golden_nonce(), 0x0037_6400);
+    /// ```
+    pub const fn golden_nonce(&self) -> u32 {
+        (self.0 & Self::GNOSWR_MASK) >> Self::GNOSWR_OFFSET
+    }
 }
 
 impl ::core::fmt::Display for GoldenNonceForSweepReturn {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("GoldenNonceForSweepReturn").finish()
+        f.debug_struct("GoldenNonceForSweepReturn")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .field(
+                "golden_nonce",
+                &format_args!("{:#010x}", self.golden_nonce()),
+            )
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for GoldenNonceForSweepReturn {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "GoldenNonceForSweepReturn {{  }}",);
+        defmt::write!(
+            fmt,
+            "GoldenNonceForSweepReturn {{ raw: {=u32:#010x}, golden_nonce: {=u32:#010x} }}",
+            self.0,
+            self.golden_nonce(),
+        );
     }
 }
 
@@ -3553,6 +6085,7 @@ impl defmt::Format for GoldenNonceForSweepReturn {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReturnedGroupPatternStatus(u32);
 impl_boilerplate_for!(ReturnedGroupPatternStatus);
 
@@ -3601,18 +6134,27 @@ impl ReturnedGroupPatternStatus {
     pub const RGPS1_MASK: u32 = 0b1111 << Self::RGPS1_OFFSET;
     /// ## Bit mask for the `RGPS0` field.
     pub const RGPS0_MASK: u32 = 0b1111 << Self::RGPS0_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 =
+        !(Self::RGPS3_MASK | Self::RGPS2_MASK | Self::RGPS1_MASK | Self::RGPS0_MASK);
 }
 
 impl ::core::fmt::Display for ReturnedGroupPatternStatus {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("ReturnedGroupPatternStatus").finish()
+        f.debug_struct("ReturnedGroupPatternStatus")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for ReturnedGroupPatternStatus {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "ReturnedGroupPatternStatus {{  }}",);
+        defmt::write!(
+            fmt,
+            "ReturnedGroupPatternStatus {{ raw: {=u32:#010x} }}",
+            self.0
+        );
     }
 }
 
@@ -3620,6 +6162,7 @@ impl defmt::Format for ReturnedGroupPatternStatus {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NonceReturnedTimeout(u32);
 impl_boilerplate_for!(NonceReturnedTimeout);
 
@@ -3656,18 +6199,77 @@ impl NonceReturnedTimeout {
 
     /// ## Bit mask for the `SWEEP_TIMEOUT` field.
     pub const SWEEP_TIMEOUT_MASK: u32 = 0xffff << Self::SWEEP_TIMEOUT_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !Self::SWEEP_TIMEOUT_MASK;
+
+    /// ## Get the Sweep Timeout.
+    ///
+    /// This returns an `u16` with the Sweep Timeout, in clock cycles.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::NonceReturnedTimeout;
+    ///
+    /// let nrt: NonceReturnedTimeout = NonceReturnedTimeout::DEFAULT;
+    /// assert_eq!(nrt.sweep_timeout(), 0xFFFF);
+    /// let nrt: NonceReturnedTimeout = nrt.set_sweep_timeout(0x1234);
+    /// assert_eq!(nrt.sweep_timeout(), 0x1234);
+    /// ```
+    pub const fn sweep_timeout(&self) -> u16 {
+        ((self.0 & Self::SWEEP_TIMEOUT_MASK) >> Self::SWEEP_TIMEOUT_OFFSET) as u16
+    }
+    /// ## Set the Sweep Timeout.
+    #[must_use = "set_sweep_timeout returns a modified NonceReturnedTimeout"]
+    pub const fn set_sweep_timeout(mut self, sweep_timeout: u16) -> Self {
+        self.0 &= !Self::SWEEP_TIMEOUT_MASK;
+        self.0 |= ((sweep_timeout as u32) << Self::SWEEP_TIMEOUT_OFFSET) & Self::SWEEP_TIMEOUT_MASK;
+        self
+    }
+
+    /// ## Get the Sweep Timeout duration.
+    ///
+    /// This returns an `MicrosDurationU32` with the Sweep Timeout duration,
+    /// according to the `clk` frequency counting it.
+    /// A null `clk` returns a null duration.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::NonceReturnedTimeout;
+    /// use fugit::{HertzU32, MicrosDurationU32};
+    ///
+    /// let nrt: NonceReturnedTimeout = NonceReturnedTimeout::DEFAULT.set_sweep_timeout(50_000);
+    /// assert_eq!(nrt.to_duration(HertzU32::MHz(25)), MicrosDurationU32::millis(2));
+    /// ```
+    pub const fn to_duration(&self, clk: HertzU32) -> fugit::MicrosDurationU32 {
+        if clk.raw() == 0 {
+            return fugit::MicrosDurationU32::from_ticks(0);
+        }
+        fugit::MicrosDurationU32::from_ticks(
+            (self.sweep_timeout() as u64 * 1_000_000 / clk.raw() as u64) as u32,
+        )
+    }
 }
 
 impl ::core::fmt::Display for NonceReturnedTimeout {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("NonceReturnedTimeout").finish()
+        f.debug_struct("NonceReturnedTimeout")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .field("sweep_timeout", &self.sweep_timeout())
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for NonceReturnedTimeout {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "NonceReturnedTimeout {{  }}",);
+        defmt::write!(
+            fmt,
+            "NonceReturnedTimeout {{ raw: {=u32:#010x}, sweep_timeout: {} }}",
+            self.0,
+            self.sweep_timeout(),
+        );
     }
 }
 
@@ -3675,6 +6277,7 @@ impl defmt::Format for NonceReturnedTimeout {
 ///
 /// Used to identify chip.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ReturnedSinglePatternStatus(u32);
 impl_boilerplate_for!(ReturnedSinglePatternStatus);
 
@@ -3711,22 +6314,339 @@ impl ReturnedSinglePatternStatus {
 
     /// ## Bit mask for the `RSPS` field.
     pub const RSPS_MASK: u32 = 0xffff_ffff << Self::RSPS_OFFSET;
+    /// ## Bit mask for the reserved bits.
+    pub const RESERVED_MASK: u32 = !Self::RSPS_MASK;
 }
 
 impl ::core::fmt::Display for ReturnedSinglePatternStatus {
     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-        f.debug_struct("ReturnedSinglePatternStatus").finish()
+        f.debug_struct("ReturnedSinglePatternStatus")
+            .field("raw", &format_args!("{:#010x}", self.0))
+            .finish()
     }
 }
 
 #[cfg(feature = "defmt")]
 impl defmt::Format for ReturnedSinglePatternStatus {
     fn format(&self, fmt: defmt::Formatter) {
-        defmt::write!(fmt, "ReturnedSinglePatternStatus {{  }}",);
+        defmt::write!(
+            fmt,
+            "ReturnedSinglePatternStatus {{ raw: {=u32:#010x} }}",
+            self.0
+        );
+    }
+}
+
+/// # Reset Values
+///
+/// The reset value of every register, by register address.
+///
+/// This can be compared to a full register dump after a chip reset to detect stuck bits.
+///
+/// ### Example
+///
+/// ```
+/// use bm1397_protocol::register::{MiscControl, RESET_VALUES};
+///
+/// assert!(RESET_VALUES.contains(&(MiscControl::ADDR, MiscControl::RESET)));
+/// ```
+pub const RESET_VALUES: &[(u8, u32)] = &[
+    (ChipAddress::ADDR, ChipAddress::RESET),
+    (HashRate::ADDR, HashRate::RESET),
+    (PLL0Parameter::ADDR, PLL0Parameter::RESET),
+    (ChipNonceOffset::ADDR, ChipNonceOffset::RESET),
+    (HashCountingNumber::ADDR, HashCountingNumber::RESET),
+    (TicketMask::ADDR, TicketMask::RESET),
+    (MiscControl::ADDR, MiscControl::RESET),
+    (I2CControl::ADDR, I2CControl::RESET),
+    (OrderedClockEnable::ADDR, OrderedClockEnable::RESET),
+    (FastUARTConfiguration::ADDR, FastUARTConfiguration::RESET),
+    (UARTRelay::ADDR, UARTRelay::RESET),
+    (TicketMask2::ADDR, TicketMask2::RESET),
+    (CoreRegisterControl::ADDR, CoreRegisterControl::RESET),
+    (CoreRegisterValue::ADDR, CoreRegisterValue::RESET),
+    (
+        ExternalTemperatureSensorRead::ADDR,
+        ExternalTemperatureSensorRead::RESET,
+    ),
+    (ErrorFlag::ADDR, ErrorFlag::RESET),
+    (NonceErrorCounter::ADDR, NonceErrorCounter::RESET),
+    (NonceOverflowCounter::ADDR, NonceOverflowCounter::RESET),
+    (AnalogMuxControl::ADDR, AnalogMuxControl::RESET),
+    (
+        IoDriverStrenghtConfiguration::ADDR,
+        IoDriverStrenghtConfiguration::RESET,
+    ),
+    (TimeOut::ADDR, TimeOut::RESET),
+    (PLL1Parameter::ADDR, PLL1Parameter::RESET),
+    (PLL2Parameter::ADDR, PLL2Parameter::RESET),
+    (PLL3Parameter::ADDR, PLL3Parameter::RESET),
+    (OrderedClockMonitor::ADDR, OrderedClockMonitor::RESET),
+    (PLL0Divider::ADDR, PLL0Divider::RESET),
+    (PLL1Divider::ADDR, PLL1Divider::RESET),
+    (PLL2Divider::ADDR, PLL2Divider::RESET),
+    (PLL3Divider::ADDR, PLL3Divider::RESET),
+    (ClockOrderControl0::ADDR, ClockOrderControl0::RESET),
+    (ClockOrderControl1::ADDR, ClockOrderControl1::RESET),
+    (ClockOrderStatus::ADDR, ClockOrderStatus::RESET),
+    (FrequencySweepControl1::ADDR, FrequencySweepControl1::RESET),
+    (
+        GoldenNonceForSweepReturn::ADDR,
+        GoldenNonceForSweepReturn::RESET,
+    ),
+    (
+        ReturnedGroupPatternStatus::ADDR,
+        ReturnedGroupPatternStatus::RESET,
+    ),
+    (NonceReturnedTimeout::ADDR, NonceReturnedTimeout::RESET),
+    (
+        ReturnedSinglePatternStatus::ADDR,
+        ReturnedSinglePatternStatus::RESET,
+    ),
+];
+
+/// # Reset Value
+///
+/// Get the reset value of the register at address `addr`.
+///
+/// This returns `None` if `addr` do not match a known register.
+///
+/// ### Example
+///
+/// ```
+/// use bm1397_protocol::register::{reset_value, ChipAddress};
+///
+/// assert_eq!(reset_value(ChipAddress::ADDR), Some(0x1397_1800));
+/// assert_eq!(reset_value(0xF0), None);
+/// ```
+pub const fn reset_value(addr: u8) -> Option<u32> {
+    let mut i = 0;
+    while i < RESET_VALUES.len() {
+        if RESET_VALUES[i].0 == addr {
+            return Some(RESET_VALUES[i].1);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// # Register Map Entry
+///
+/// An uniform `(addr, value, writable)` view of a register, to bridge the typed
+/// registers with a table-driven register map.
+///
+/// ### Example
+///
+/// ```
+/// use bm1397_protocol::register::{ErrorFlag, MiscControl, RegisterEntry, Registers};
+///
+/// let entry = RegisterEntry::from(&Registers::MiscControl(MiscControl::DEFAULT));
+/// assert_eq!(entry, RegisterEntry { addr: 0x18, value: 0x0000_3A01, writable: true });
+/// let entry = RegisterEntry::from(&Registers::ErrorFlag(ErrorFlag::DEFAULT));
+/// assert!(!entry.writable);
+///
+/// // the reset map of the chip
+/// let map = RegisterEntry::reset_map();
+/// assert_eq!(map.clone().count(), 37);
+/// assert!(map.clone().any(|e| e == RegisterEntry::from(&Registers::MiscControl(MiscControl::DEFAULT))));
+/// ```
+#[cfg(feature = "register-map")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RegisterEntry {
+    pub addr: u8,
+    pub value: u32,
+    pub writable: bool,
+}
+
+#[cfg(feature = "register-map")]
+impl RegisterEntry {
+    /// Create the entry of the register at address `addr` with the raw `value`.
+    pub const fn new(addr: u8, value: u32) -> Self {
+        Self {
+            addr,
+            value,
+            writable: is_writable(addr),
+        }
+    }
+
+    /// Get the entries of all the registers with their reset value, see `RESET_VALUES`.
+    pub fn reset_map() -> impl Iterator<Item = RegisterEntry> + Clone {
+        RESET_VALUES
+            .iter()
+            .map(|&(addr, value)| RegisterEntry::new(addr, value))
+    }
+}
+
+#[cfg(feature = "register-map")]
+impl From<&Registers> for RegisterEntry {
+    fn from(reg: &Registers) -> Self {
+        RegisterEntry::new(reg.addr(), reg.val())
+    }
+}
+
+/// # PLL VCO maximum frequency
+///
+/// The highest VCO frequency (`clki_freq * fbdiv / refdiv`) used by [`pll0_for_frequency`].
+///
+/// The reset value of `PLL0Parameter` run its VCO at 2.4 GHz, and known firmwares
+/// do not go above 2.5 GHz.
+pub const PLL_VCO_MAX: HertzU32 = HertzU32::MHz(2_500);
+
+/// # PLL0 for Frequency
+///
+/// Compute the `PLL0Parameter` and `PLL0Divider` pair giving the closest frequency to
+/// `target` on the PLL0 divider output 0.
+///
+/// The dividers are searched for the smallest frequency error, with `refdiv` in `1..=2`,
+/// `postdiv1` in `1..=7`, `postdiv2` not greater than `postdiv1` and the VCO not greater
+/// than [`PLL_VCO_MAX`]. For the same error, the highest VCO then the highest `postdiv1`
+/// are preferred, like the reset value.
+/// `PLLDIV0` is set to 0 (no division) and the other outputs to their slowest setting.
+///
+/// This returns `None` if `target` is null or can not be reached under the VCO limit.
+///
+/// ### Example
+///
+/// ```
+/// use bm1397_protocol::register::*;
+/// use fugit::HertzU32;
+///
+/// let (pll0, pll0_div) = pll0_for_frequency(HertzU32::MHz(25), HertzU32::MHz(400)).unwrap();
+/// assert_eq!(pll0, PLL0Parameter::DEFAULT.unlock());
+/// assert_eq!(pll0_div.plldiv(0), 0);
+///
+/// let (pll0, pll0_div) = pll0_for_frequency(HertzU32::MHz(25), HertzU32::MHz(525)).unwrap();
+/// assert_eq!(pll0.frequency(HertzU32::MHz(25)), HertzU32::MHz(525));
+/// assert_eq!(pll0_div.plldiv(0), 0);
+///
+/// // only reachable with refdiv 2, with a fbdiv above 171
+/// let (pll0, _) = pll0_for_frequency(HertzU32::MHz(25), HertzU32::kHz(99_500)).unwrap();
+/// assert_eq!((pll0.fbdiv(), pll0.refdiv(), pll0.postdiv1(), pll0.postdiv2()), (199, 2, 5, 5));
+/// assert_eq!(pll0.frequency(HertzU32::MHz(25)), HertzU32::kHz(99_500));
+///
+/// assert_eq!(pll0_for_frequency(HertzU32::MHz(25), HertzU32::MHz(3_000)), None);
+/// ```
+pub fn pll0_for_frequency(
+    clki_freq: HertzU32,
+    target: HertzU32,
+) -> Option<(PLL0Parameter, PLL0Divider)> {
+    let (clki, target) = (clki_freq.raw() as u64, target.raw() as u64);
+    if clki == 0 || target == 0 {
+        return None;
+    }
+    // (error, vco, pll0)
+    let mut best: Option<(u64, u64, PLL0Parameter)> = None;
+    for refdiv in 1..=2u8 {
+        for postdiv1 in (1..=7u8).rev() {
+            for postdiv2 in 1..=postdiv1 {
+                let divider = refdiv as u64 * postdiv1 as u64 * postdiv2 as u64;
+                let fbdiv = (target * divider + clki / 2) / clki;
+                let vco = clki * fbdiv / refdiv as u64;
+                if fbdiv == 0 || fbdiv > 0xfff || vco > PLL_VCO_MAX.raw() as u64 {
+                    continue;
+                }
+                let err = (clki * fbdiv / divider).abs_diff(target);
+                if best.is_none_or(|(e, v, _)| err < e || (err == e && vco > v)) {
+                    let pll0 =
+                        PLL0Parameter::from_dividers(fbdiv as u16, refdiv, postdiv1, postdiv2);
+                    best = Some((err, vco, pll0));
+                }
+            }
+        }
+    }
+    best.map(|(_, _, pll0)| (pll0, PLL0Divider(0x0F0F_0F00)))
+}
+
+/// # Frequency Preset
+///
+/// A BM1397 operating point : the PLL0 setting giving `frequency` on the PLL0
+/// divider output 0 from the standard 25 MHz CLKI, see [`FREQUENCY_PRESETS`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct FrequencyPreset {
+    /// The hash frequency, at 25 MHz CLKI.
+    pub frequency: HertzU32,
+    pub pll0: PLL0Parameter,
+    pub pll0_divider: PLL0Divider,
+}
+
+impl FrequencyPreset {
+    /// ## Expected hashrate.
+    ///
+    /// This returns the theoretical hashrate of the chip in hashes per second, each small
+    /// core computing one hash per hash clock cycle : `frequency * SMALL_CORE_COUNT`.
+    ///
+    /// ### Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::FREQUENCY_PRESETS;
+    ///
+    /// // 400 MHz * 672 small cores
+    /// assert_eq!(FREQUENCY_PRESETS[0].expected_hashrate(), 268_800_000_000);
+    /// ```
+    pub const fn expected_hashrate(&self) -> u64 {
+        self.frequency.raw() as u64 * crate::SMALL_CORE_COUNT as u64
     }
 }
 
-#[derive(Debug, PartialEq)]
+/// # Frequency Presets
+///
+/// Common BM1397 operating points, by increasing frequency, with the exact PLL0
+/// dividers (no frequency error, VCO not greater than [`PLL_VCO_MAX`]) at 25 MHz CLKI.
+/// These are the values computed by [`pll0_for_frequency`].
+///
+/// The presets only cover the clock setting : the frequencies above the reset one (400 MHz)
+/// need an adequate core voltage and cooling, which are board specific.
+///
+/// ### Example
+///
+/// ```
+/// use bm1397_protocol::register::{pll0_for_frequency, FREQUENCY_PRESETS};
+/// use fugit::HertzU32;
+///
+/// for preset in FREQUENCY_PRESETS {
+///     assert_eq!(preset.pll0.frequency(HertzU32::MHz(25)), preset.frequency);
+///     assert_eq!(
+///         pll0_for_frequency(HertzU32::MHz(25), preset.frequency),
+///         Some((preset.pll0, preset.pll0_divider))
+///     );
+/// }
+/// ```
+pub const FREQUENCY_PRESETS: &[FrequencyPreset] = &[
+    FrequencyPreset {
+        frequency: HertzU32::MHz(400),
+        pll0: PLL0Parameter::from_dividers(96, 1, 6, 1),
+        pll0_divider: PLL0Divider(0x0F0F_0F00),
+    },
+    FrequencyPreset {
+        frequency: HertzU32::MHz(450),
+        pll0: PLL0Parameter::from_dividers(90, 1, 5, 1),
+        pll0_divider: PLL0Divider(0x0F0F_0F00),
+    },
+    FrequencyPreset {
+        frequency: HertzU32::MHz(500),
+        pll0: PLL0Parameter::from_dividers(100, 1, 5, 1),
+        pll0_divider: PLL0Divider(0x0F0F_0F00),
+    },
+    FrequencyPreset {
+        frequency: HertzU32::MHz(525),
+        pll0: PLL0Parameter::from_dividers(84, 1, 4, 1),
+        pll0_divider: PLL0Divider(0x0F0F_0F00),
+    },
+    FrequencyPreset {
+        frequency: HertzU32::MHz(550),
+        pll0: PLL0Parameter::from_dividers(88, 1, 4, 1),
+        pll0_divider: PLL0Divider(0x0F0F_0F00),
+    },
+    FrequencyPreset {
+        frequency: HertzU32::MHz(600),
+        pll0: PLL0Parameter::from_dividers(96, 1, 4, 1),
+        pll0_divider: PLL0Divider(0x0F0F_0F00),
+    },
+];
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Registers {
     ChipAddress(ChipAddress),
     HashRate(HashRate),
@@ -3766,3 +6686,406 @@ pub enum Registers {
     NonceReturnedTimeout(NonceReturnedTimeout),
     ReturnedSinglePatternStatus(ReturnedSinglePatternStatus),
 }
+
+impl Registers {
+    /// Get the register as a `Register` trait object.
+    fn as_register(&self) -> &dyn Register {
+        match self {
+            Registers::ChipAddress(r) => r,
+            Registers::HashRate(r) => r,
+            Registers::PLL0Parameter(r) => r,
+            Registers::ChipNonceOffset(r) => r,
+            Registers::HashCountingNumber(r) => r,
+            Registers::TicketMask(r) => r,
+            Registers::MiscControl(r) => r,
+            Registers::I2CControl(r) => r,
+            Registers::OrderedClockEnable(r) => r,
+            Registers::FastUARTConfiguration(r) => r,
+            Registers::UARTRelay(r) => r,
+            Registers::TicketMask2(r) => r,
+            Registers::CoreRegisterControl(r) => r,
+            Registers::CoreRegisterValue(r) => r,
+            Registers::ExternalTemperatureSensorRead(r) => r,
+            Registers::ErrorFlag(r) => r,
+            Registers::NonceErrorCounter(r) => r,
+            Registers::NonceOverflowCounter(r) => r,
+            Registers::AnalogMuxControl(r) => r,
+            Registers::IoDriverStrenghtConfiguration(r) => r,
+            Registers::TimeOut(r) => r,
+            Registers::PLL1Parameter(r) => r,
+            Registers::PLL2Parameter(r) => r,
+            Registers::PLL3Parameter(r) => r,
+            Registers::OrderedClockMonitor(r) => r,
+            Registers::PLL0Divider(r) => r,
+            Registers::PLL1Divider(r) => r,
+            Registers::PLL2Divider(r) => r,
+            Registers::PLL3Divider(r) => r,
+            Registers::ClockOrderControl0(r) => r,
+            Registers::ClockOrderControl1(r) => r,
+            Registers::ClockOrderStatus(r) => r,
+            Registers::FrequencySweepControl1(r) => r,
+            Registers::GoldenNonceForSweepReturn(r) => r,
+            Registers::ReturnedGroupPatternStatus(r) => r,
+            Registers::NonceReturnedTimeout(r) => r,
+            Registers::ReturnedSinglePatternStatus(r) => r,
+        }
+    }
+
+    /// Check the reserved bits of the inner register, see the `reserved_ok` of each register.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::{MiscControl, Registers};
+    ///
+    /// assert!(Registers::MiscControl(MiscControl::DEFAULT).reserved_ok());
+    /// assert!(!Registers::MiscControl(MiscControl::from(0xFFFF_FFFF)).reserved_ok());
+    /// ```
+    pub const fn reserved_ok(&self) -> bool {
+        match self {
+            Registers::ChipAddress(r) => r.reserved_ok(),
+            Registers::HashRate(r) => r.reserved_ok(),
+            Registers::PLL0Parameter(r) => r.reserved_ok(),
+            Registers::ChipNonceOffset(r) => r.reserved_ok(),
+            Registers::HashCountingNumber(r) => r.reserved_ok(),
+            Registers::TicketMask(r) => r.reserved_ok(),
+            Registers::MiscControl(r) => r.reserved_ok(),
+            Registers::I2CControl(r) => r.reserved_ok(),
+            Registers::OrderedClockEnable(r) => r.reserved_ok(),
+            Registers::FastUARTConfiguration(r) => r.reserved_ok(),
+            Registers::UARTRelay(r) => r.reserved_ok(),
+            Registers::TicketMask2(r) => r.reserved_ok(),
+            Registers::CoreRegisterControl(r) => r.reserved_ok(),
+            Registers::CoreRegisterValue(r) => r.reserved_ok(),
+            Registers::ExternalTemperatureSensorRead(r) => r.reserved_ok(),
+            Registers::ErrorFlag(r) => r.reserved_ok(),
+            Registers::NonceErrorCounter(r) => r.reserved_ok(),
+            Registers::NonceOverflowCounter(r) => r.reserved_ok(),
+            Registers::AnalogMuxControl(r) => r.reserved_ok(),
+            Registers::IoDriverStrenghtConfiguration(r) => r.reserved_ok(),
+            Registers::TimeOut(r) => r.reserved_ok(),
+            Registers::PLL1Parameter(r) => r.reserved_ok(),
+            Registers::PLL2Parameter(r) => r.reserved_ok(),
+            Registers::PLL3Parameter(r) => r.reserved_ok(),
+            Registers::OrderedClockMonitor(r) => r.reserved_ok(),
+            Registers::PLL0Divider(r) => r.reserved_ok(),
+            Registers::PLL1Divider(r) => r.reserved_ok(),
+            Registers::PLL2Divider(r) => r.reserved_ok(),
+            Registers::PLL3Divider(r) => r.reserved_ok(),
+            Registers::ClockOrderControl0(r) => r.reserved_ok(),
+            Registers::ClockOrderControl1(r) => r.reserved_ok(),
+            Registers::ClockOrderStatus(r) => r.reserved_ok(),
+            Registers::FrequencySweepControl1(r) => r.reserved_ok(),
+            Registers::GoldenNonceForSweepReturn(r) => r.reserved_ok(),
+            Registers::ReturnedGroupPatternStatus(r) => r.reserved_ok(),
+            Registers::NonceReturnedTimeout(r) => r.reserved_ok(),
+            Registers::ReturnedSinglePatternStatus(r) => r.reserved_ok(),
+        }
+    }
+}
+
+impl ::core::fmt::Display for Registers {
+    /// Display the inner register.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::{ChipAddress, Registers};
+    ///
+    /// let reg = Registers::ChipAddress(ChipAddress::DEFAULT);
+    /// assert_eq!(format!("{}", reg), format!("{}", ChipAddress::DEFAULT));
+    /// ```
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        match self {
+            Registers::ChipAddress(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::HashRate(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::PLL0Parameter(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::ChipNonceOffset(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::HashCountingNumber(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::TicketMask(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::MiscControl(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::I2CControl(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::OrderedClockEnable(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::FastUARTConfiguration(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::UARTRelay(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::TicketMask2(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::CoreRegisterControl(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::CoreRegisterValue(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::ExternalTemperatureSensorRead(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::ErrorFlag(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::NonceErrorCounter(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::NonceOverflowCounter(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::AnalogMuxControl(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::IoDriverStrenghtConfiguration(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::TimeOut(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::PLL1Parameter(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::PLL2Parameter(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::PLL3Parameter(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::OrderedClockMonitor(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::PLL0Divider(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::PLL1Divider(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::PLL2Divider(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::PLL3Divider(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::ClockOrderControl0(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::ClockOrderControl1(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::ClockOrderStatus(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::FrequencySweepControl1(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::GoldenNonceForSweepReturn(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::ReturnedGroupPatternStatus(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::NonceReturnedTimeout(r) => ::core::fmt::Display::fmt(r, f),
+            Registers::ReturnedSinglePatternStatus(r) => ::core::fmt::Display::fmt(r, f),
+        }
+    }
+}
+
+impl Register for Registers {
+    /// Get the address of the register.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::{MiscControl, Register, Registers};
+    ///
+    /// let reg = Registers::MiscControl(MiscControl::DEFAULT);
+    /// assert_eq!(reg.addr(), MiscControl::ADDR);
+    /// assert_eq!(reg.val(), MiscControl::RESET);
+    /// ```
+    fn addr(&self) -> u8 {
+        self.as_register().addr()
+    }
+    fn val(&self) -> u32 {
+        self.as_register().val()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crc::crc5;
+    use crate::response::{Response, ResponseType};
+
+    /// Test the reset values table against each register `RESET` and `DEFAULT`.
+    #[test]
+    fn reset_values() {
+        macro_rules! check_reset {
+            ($REG:ident) => {
+                assert_eq!(reset_value($REG::ADDR), Some($REG::RESET));
+                assert_eq!(reset_value($REG::ADDR), Some($REG::DEFAULT.val()));
+                assert_eq!(reset_value($REG::ADDR), Some($REG::DEFAULT.raw()));
+                assert!($REG::DEFAULT.reserved_ok());
+                assert_eq!($REG::DEFAULT, $REG::RESET);
+            };
+        }
+        check_reset!(ChipAddress);
+        check_reset!(HashRate);
+        check_reset!(PLL0Parameter);
+        check_reset!(ChipNonceOffset);
+        check_reset!(HashCountingNumber);
+        check_reset!(TicketMask);
+        check_reset!(MiscControl);
+        check_reset!(I2CControl);
+        check_reset!(OrderedClockEnable);
+        check_reset!(FastUARTConfiguration);
+        check_reset!(UARTRelay);
+        check_reset!(TicketMask2);
+        check_reset!(CoreRegisterControl);
+        check_reset!(CoreRegisterValue);
+        check_reset!(ExternalTemperatureSensorRead);
+        check_reset!(ErrorFlag);
+        check_reset!(NonceErrorCounter);
+        check_reset!(NonceOverflowCounter);
+        check_reset!(AnalogMuxControl);
+        check_reset!(IoDriverStrenghtConfiguration);
+        check_reset!(TimeOut);
+        check_reset!(PLL1Parameter);
+        check_reset!(PLL2Parameter);
+        check_reset!(PLL3Parameter);
+        check_reset!(OrderedClockMonitor);
+        check_reset!(PLL0Divider);
+        check_reset!(PLL1Divider);
+        check_reset!(PLL2Divider);
+        check_reset!(PLL3Divider);
+        check_reset!(ClockOrderControl0);
+        check_reset!(ClockOrderControl1);
+        check_reset!(ClockOrderStatus);
+        check_reset!(FrequencySweepControl1);
+        check_reset!(GoldenNonceForSweepReturn);
+        check_reset!(ReturnedGroupPatternStatus);
+        check_reset!(NonceReturnedTimeout);
+        check_reset!(ReturnedSinglePatternStatus);
+        assert_eq!(RESET_VALUES.len(), 37);
+    }
+
+    /// Test that the `MiscControl` `TFS` and `HASHRATE_TWS` masks cover their own
+    /// field bits, outside of the reserved bits.
+    #[test]
+    fn misc_control_masks() {
+        assert_eq!(MiscControl::TFS_MASK, 0x0000_0070);
+        assert_eq!(MiscControl::HASHRATE_TWS_MASK, 0x0000_0003);
+        assert_eq!(MiscControl::TFS_MASK & MiscControl::HASHRATE_TWS_MASK, 0);
+        assert_eq!(MiscControl::TFS_MASK & MiscControl::RESERVED_MASK, 0);
+        assert_eq!(
+            MiscControl::HASHRATE_TWS_MASK & MiscControl::RESERVED_MASK,
+            0
+        );
+    }
+
+    /// Test that every register implement the common set of derives,
+    /// so they can all be stored in hash-based collections.
+    #[test]
+    fn common_derives() {
+        fn check_derives<T: core::fmt::Debug + Copy + Clone + Eq + PartialEq + core::hash::Hash>() {
+        }
+        check_derives::<ChipAddress>();
+        check_derives::<HashRate>();
+        check_derives::<PLL0Parameter>();
+        check_derives::<ChipNonceOffset>();
+        check_derives::<HashCountingNumber>();
+        check_derives::<TicketMask>();
+        check_derives::<MiscControl>();
+        check_derives::<I2CControl>();
+        check_derives::<OrderedClockEnable>();
+        check_derives::<FastUARTConfiguration>();
+        check_derives::<UARTRelay>();
+        check_derives::<TicketMask2>();
+        check_derives::<CoreRegisterControl>();
+        check_derives::<CoreRegisterValue>();
+        check_derives::<ExternalTemperatureSensorRead>();
+        check_derives::<ErrorFlag>();
+        check_derives::<NonceErrorCounter>();
+        check_derives::<NonceOverflowCounter>();
+        check_derives::<AnalogMuxControl>();
+        check_derives::<IoDriverStrenghtConfiguration>();
+        check_derives::<TimeOut>();
+        check_derives::<PLL1Parameter>();
+        check_derives::<PLL2Parameter>();
+        check_derives::<PLL3Parameter>();
+        check_derives::<OrderedClockMonitor>();
+        check_derives::<PLL0Divider>();
+        check_derives::<PLL1Divider>();
+        check_derives::<PLL2Divider>();
+        check_derives::<PLL3Divider>();
+        check_derives::<ClockOrderControl0>();
+        check_derives::<ClockOrderControl1>();
+        check_derives::<ClockOrderStatus>();
+        check_derives::<FrequencySweepControl1>();
+        check_derives::<GoldenNonceForSweepReturn>();
+        check_derives::<ReturnedGroupPatternStatus>();
+        check_derives::<NonceReturnedTimeout>();
+        check_derives::<ReturnedSinglePatternStatus>();
+        check_derives::<Registers>();
+    }
+
+    /// Test that all the representations of the `BCK_SEL` bit agree,
+    /// for each `BaudrateClockSelect`.
+    #[test]
+    fn bclk_sel_round_trip() {
+        for (sel, bit) in [
+            (BaudrateClockSelect::Clki, false),
+            (BaudrateClockSelect::Pll3, true),
+        ] {
+            for base in [MiscControl::DEFAULT, MiscControl::from(!0)] {
+                let misc = base.set_bclk_sel(sel);
+                assert_eq!(misc.bclk_sel(), sel);
+                assert_eq!(misc.val() & MiscControl::BCK_SEL_MASK != 0, bit);
+                assert_eq!(
+                    misc.val() & !MiscControl::BCK_SEL_MASK,
+                    base.val() & !MiscControl::BCK_SEL_MASK
+                );
+                assert_eq!(BaudrateClockSelect::from(bit), sel);
+                assert_eq!(bool::from(sel), bit);
+                assert_eq!(u8::from(sel), bit as u8);
+                assert_eq!(
+                    ((misc.val() & MiscControl::BCK_SEL_MASK) >> MiscControl::BCK_SEL_OFFSET) as u8,
+                    u8::from(sel)
+                );
+            }
+        }
+    }
+
+    /// Test the partial update of a register with `with_field_from`.
+    #[test]
+    fn with_field_from() {
+        let read = MiscControl::from(0x0040_3A01);
+        let wanted = MiscControl::DEFAULT
+            .set_bclk_sel(BaudrateClockSelect::Pll3)
+            .set_bt8d(7);
+        let merged = read.with_field_from(
+            &wanted,
+            MiscControl::BCK_SEL_MASK | MiscControl::BT8D_8_5_MASK | MiscControl::BT8D_4_0_MASK,
+        );
+        assert_eq!(merged, 0x0041_2701);
+        assert!(merged.core_srst());
+        assert_eq!(read.with_field_from(&wanted, 0), read);
+        assert_eq!(read.with_field_from(&wanted, u32::MAX), wanted);
+
+        let pll = PLL0Parameter::from(0xC060_0161);
+        let merged = pll.with_field_from(&pll.set_fbdiv(0x80), PLL0Parameter::FBDIV_MASK);
+        assert_eq!(merged.fbdiv(), 0x80);
+        assert!(merged.locked());
+    }
+
+    /// Test that the register addresses are all distinct, and that `Response::parse`
+    /// decode each of them as its own register.
+    #[test]
+    fn register_addresses() {
+        for (i, (addr, _)) in RESET_VALUES.iter().enumerate() {
+            assert!(
+                RESET_VALUES[i + 1..].iter().all(|(a, _)| a != addr),
+                "duplicate register address {addr:#04x}"
+            );
+        }
+        macro_rules! check_parse {
+            ($REG:ident) => {
+                let [b0, b1, b2, b3] = $REG::RESET.to_be_bytes();
+                let mut frame = [0xAA, 0x55, b0, b1, b2, b3, 0x00, $REG::ADDR, 0x00];
+                frame[8] = (0..0x20)
+                    .find(|crc| crc5(&[b0, b1, b2, b3, 0x00, $REG::ADDR, *crc]) == 0)
+                    .unwrap();
+                match Response::parse(&frame) {
+                    Ok(ResponseType::Reg(r)) => {
+                        assert_eq!(r.register, Registers::$REG($REG::DEFAULT))
+                    }
+                    _ => panic!("{} not parsed", stringify!($REG)),
+                }
+            };
+        }
+        check_parse!(ChipAddress);
+        check_parse!(HashRate);
+        check_parse!(PLL0Parameter);
+        check_parse!(ChipNonceOffset);
+        check_parse!(HashCountingNumber);
+        check_parse!(TicketMask);
+        check_parse!(MiscControl);
+        check_parse!(I2CControl);
+        check_parse!(OrderedClockEnable);
+        check_parse!(FastUARTConfiguration);
+        check_parse!(UARTRelay);
+        check_parse!(TicketMask2);
+        check_parse!(CoreRegisterControl);
+        check_parse!(CoreRegisterValue);
+        check_parse!(ExternalTemperatureSensorRead);
+        check_parse!(ErrorFlag);
+        check_parse!(NonceErrorCounter);
+        check_parse!(NonceOverflowCounter);
+        check_parse!(AnalogMuxControl);
+        check_parse!(IoDriverStrenghtConfiguration);
+        check_parse!(TimeOut);
+        check_parse!(PLL1Parameter);
+        check_parse!(PLL2Parameter);
+        check_parse!(PLL3Parameter);
+        check_parse!(OrderedClockMonitor);
+        check_parse!(PLL0Divider);
+        check_parse!(PLL1Divider);
+        check_parse!(PLL2Divider);
+        check_parse!(PLL3Divider);
+        check_parse!(ClockOrderControl0);
+        check_parse!(ClockOrderControl1);
+        check_parse!(ClockOrderStatus);
+        check_parse!(FrequencySweepControl1);
+        check_parse!(GoldenNonceForSweepReturn);
+        check_parse!(ReturnedGroupPatternStatus);
+        check_parse!(NonceReturnedTimeout);
+        check_parse!(ReturnedSinglePatternStatus);
+    }
+}
@@ -2,39 +2,688 @@
 
 use crate::crc::crc5;
 use crate::register::*;
-use crate::Error;
+use crate::{
+    Error, NONCE_CHIP_ADDR_MASK, NONCE_CHIP_ADDR_OFFSET, NONCE_CORE_ID_MASK, NONCE_CORE_ID_OFFSET,
+};
+
+/// Length in bytes of a response frame, preamble and CRC5 included.
+///
+/// ## Example
+///
+/// ```
+/// use bm1397_protocol::response::{Response, RESPONSE_LEN};
+///
+/// let frame: [u8; RESPONSE_LEN] = [0xAA, 0x55, 0x13, 0x97, 0x18, 0x00, 0x00, 0x00, 0x06];
+/// assert!(Response::parse(&frame).is_ok());
+/// ```
+pub const RESPONSE_LEN: usize = 9;
+
+/// Preamble of a response frame.
+///
+/// Note this is the opposite order of the `0x55 0xAA` command preamble.
+pub const RESPONSE_PREAMBLE: [u8; 2] = [0xAA, 0x55];
+
+/// Length in bytes of a `JobResponse` frame.
+pub const JOB_RESPONSE_LEN: usize = RESPONSE_LEN;
+
+/// Length in bytes of a `RegisterResponse` frame.
+pub const REG_RESPONSE_LEN: usize = RESPONSE_LEN;
+
+/// Result of `Response::parse_prefix` : the response or the error, with the leftover bytes.
+pub type PrefixResult<'a> = Result<(ResponseType, &'a [u8]), (Error, &'a [u8])>;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct RegisterResponse {
     pub chip_addr: u8,
     pub register: Registers,
 }
 
+impl RegisterResponse {
+    /// Get the address of the register, whatever its type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bm1397_protocol::register::{MiscControl, Registers};
+    /// use bm1397_protocol::response::{Response, ResponseType};
+    ///
+    /// let frame = Response::encode_reg(0x08, &MiscControl::DEFAULT);
+    /// let Ok(ResponseType::Reg(resp)) = Response::parse(&frame) else { panic!() };
+    /// assert_eq!(resp.address(), MiscControl::ADDR);
+    /// assert_eq!(resp.value(), MiscControl::RESET);
+    /// ```
+    pub fn address(&self) -> u8 {
+        self.register.addr()
+    }
+
+    /// Get the raw value of the register, whatever its type.
+    pub fn value(&self) -> u32 {
+        self.register.val()
+    }
+
+    /// Check that the register value is plausible, to reject a corrupted frame
+    /// whose 5-bits CRC5 is valid by chance.
+    ///
+    /// The checks are cheap and conservative :
+    /// - the reserved bits of the register still have their reset value,
+    /// - `ChipAddress::chip_id` is `0x1397`,
+    /// - the dividers of an enabled `PLLnParameter` are not null.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bm1397_protocol::register::{ChipAddress, PLL0Parameter, Registers};
+    /// use bm1397_protocol::response::RegisterResponse;
+    ///
+    /// let resp = RegisterResponse { chip_addr: 0, register: Registers::ChipAddress(ChipAddress::DEFAULT) };
+    /// assert!(resp.is_plausible());
+    /// let resp = RegisterResponse { chip_addr: 0, register: Registers::ChipAddress(ChipAddress::from(0x1398_1800)) };
+    /// assert!(!resp.is_plausible());
+    /// let resp = RegisterResponse {
+    ///     chip_addr: 0,
+    ///     register: Registers::PLL0Parameter(PLL0Parameter::from_dividers(0x60, 0, 6, 1)),
+    /// };
+    /// assert!(!resp.is_plausible());
+    /// ```
+    pub const fn is_plausible(&self) -> bool {
+        if !self.register.reserved_ok() {
+            return false;
+        }
+        match &self.register {
+            Registers::ChipAddress(r) => r.chip_id() == 0x1397,
+            Registers::PLL0Parameter(r) => !r.enabled() || r.divider_ratio().1 != 0,
+            Registers::PLL1Parameter(r) => !r.enabled() || r.divider_ratio().1 != 0,
+            Registers::PLL2Parameter(r) => !r.enabled() || r.divider_ratio().1 != 0,
+            Registers::PLL3Parameter(r) => !r.enabled() || r.divider_ratio().1 != 0,
+            _ => true,
+        }
+    }
+}
+
 #[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct JobResponse {
     pub nonce: u32,
     pub job_id: u8,
     pub midstate_id: u8,
 }
 
+impl JobResponse {
+    /// Combine the `midstate_id` and the `job_id` in a single work identifier.
+    ///
+    /// The packing follow the order the chip send them in the response :
+    /// `midstate_id` in the MSB and `job_id` in the LSB.
+    /// It can be reversed with `work_id.to_be_bytes()`, giving `[midstate_id, job_id]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bm1397_protocol::response::JobResponse;
+    ///
+    /// let resp = JobResponse { nonce: 0x97C3_28B6, job_id: 0x63, midstate_id: 1 };
+    /// assert_eq!(resp.work_id(), 0x0163);
+    /// let [midstate_id, job_id] = resp.work_id().to_be_bytes();
+    /// assert_eq!(midstate_id, 1);
+    /// assert_eq!(job_id, 0x63);
+    /// ```
+    pub const fn work_id(&self) -> u16 {
+        u16::from_be_bytes([self.midstate_id, self.job_id])
+    }
+
+    /// Get the index of the midstate which produced the nonce, checked against the
+    /// `midstate_count` of the job.
+    ///
+    /// Returns `None` if `midstate_id` is out of `0..midstate_count`, which signal a
+    /// framing problem or a response to another job.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bm1397_protocol::response::JobResponse;
+    ///
+    /// let resp = JobResponse { nonce: 0x97C3_28B6, job_id: 0x63, midstate_id: 3 };
+    /// assert_eq!(resp.matched_midstate(4), Some(3));
+    /// assert_eq!(resp.matched_midstate(1), None);
+    /// let resp = JobResponse { nonce: 0x97C3_28B6, job_id: 0x63, midstate_id: 7 };
+    /// assert_eq!(resp.matched_midstate(4), None);
+    /// ```
+    pub const fn matched_midstate(&self, midstate_count: u8) -> Option<u8> {
+        if self.midstate_id < midstate_count {
+            Some(self.midstate_id)
+        } else {
+            None
+        }
+    }
+}
+
+/// # Chain Enumeration
+///
+/// Aggregate the `RegisterResponse`s of a chain enumeration, ie the responses to
+/// `Command::read_reg(ChipAddress::default(), Destination::All)` : each chip answer with
+/// one `ChipAddress` frame.
+///
+/// Only the `ChipAddress` responses are counted, per `chip_addr`.
+/// Before `Command::set_chip_addr`, all the chips answer with `chip_addr` 0, so
+/// `chip_count` is the chain length and every chip collide on address 0.
+///
+/// ## Example
+///
+/// ```
+/// use bm1397_protocol::register::{ChipAddress, Registers};
+/// use bm1397_protocol::response::{ChainEnumeration, RegisterResponse};
+///
+/// let resp = |chip_addr| RegisterResponse {
+///     chip_addr,
+///     register: Registers::ChipAddress(ChipAddress::default()),
+/// };
+/// let chain: ChainEnumeration = [resp(0), resp(2), resp(2), resp(4)].into_iter().collect();
+/// assert_eq!(chain.chip_count(), 4);
+/// assert!(chain.addresses().eq([0, 2, 4]));
+/// assert!(chain.collisions().eq([2]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChainEnumeration {
+    /// Number of `ChipAddress` responses per `chip_addr`.
+    counts: [u8; 256],
+}
+
+impl Default for ChainEnumeration {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChainEnumeration {
+    /// Create an empty enumeration.
+    pub const fn new() -> Self {
+        Self { counts: [0; 256] }
+    }
+
+    /// Add a response to the enumeration.
+    ///
+    /// Return `false` (and ignore it) if the response is not a `ChipAddress` one.
+    pub fn push(&mut self, resp: &RegisterResponse) -> bool {
+        if !matches!(resp.register, Registers::ChipAddress(_)) {
+            return false;
+        }
+        let count = &mut self.counts[resp.chip_addr as usize];
+        *count = count.saturating_add(1);
+        true
+    }
+
+    /// Number of chips that answered, ie the chain length.
+    pub fn chip_count(&self) -> usize {
+        self.counts.iter().map(|c| *c as usize).sum()
+    }
+
+    /// Distinct chip addresses that answered, in increasing order.
+    pub fn addresses(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..=0xFF).filter(|addr| self.counts[*addr as usize] > 0)
+    }
+
+    /// Chip addresses that answered more than once, in increasing order.
+    pub fn collisions(&self) -> impl Iterator<Item = u8> + '_ {
+        (0..=0xFF).filter(|addr| self.counts[*addr as usize] > 1)
+    }
+}
+
+impl FromIterator<RegisterResponse> for ChainEnumeration {
+    fn from_iter<I: IntoIterator<Item = RegisterResponse>>(iter: I) -> Self {
+        let mut chain = Self::new();
+        for resp in iter {
+            chain.push(&resp);
+        }
+        chain
+    }
+}
+
+/// # Unique Chips
+///
+/// Get the chain roster from the responses of a chain enumeration : the distinct
+/// `chip_addr` of the `ChipAddress` responses, sorted in increasing order.
+///
+/// Other responses are ignored, like in `ChainEnumeration`.
+///
+/// This needs the `heapless` feature.
+///
+/// ## Example
+///
+/// ```
+/// use bm1397_protocol::register::{ChipAddress, Registers};
+/// use bm1397_protocol::response::{unique_chips, RegisterResponse};
+///
+/// let resp = |chip_addr| RegisterResponse {
+///     chip_addr,
+///     register: Registers::ChipAddress(ChipAddress::default()),
+/// };
+/// let chips = unique_chips(&[resp(4), resp(0), resp(2), resp(2)]);
+/// assert_eq!(chips.as_slice(), &[0, 2, 4]);
+/// ```
+#[cfg(feature = "heapless")]
+pub fn unique_chips(responses: &[RegisterResponse]) -> heapless::Vec<u8, 256> {
+    let mut chain = ChainEnumeration::new();
+    for resp in responses {
+        chain.push(resp);
+    }
+    // at most 256 distinct addresses, the capacity can not overflow
+    chain.addresses().collect()
+}
+
 #[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum ResponseType {
     Reg(RegisterResponse),
     Job(JobResponse),
 }
 
+impl ResponseType {
+    /// Get the `JobResponse` if this is a job response.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::response::{Response, ResponseType};
+    ///
+    /// let resp = Response::parse(&[0xAA,0x55,0x97,0xC3,0x28,0xB6,0x01,0x63,0x9C]).unwrap();
+    /// assert_eq!(resp.as_job().unwrap().nonce, 0x97C3_28B6);
+    /// assert!(resp.as_reg().is_none());
+    /// assert_eq!(resp.into_job().unwrap().job_id, 0x63);
+    /// ```
+    pub const fn as_job(&self) -> Option<&JobResponse> {
+        match self {
+            ResponseType::Job(j) => Some(j),
+            ResponseType::Reg(_) => None,
+        }
+    }
+
+    /// Get the `RegisterResponse` if this is a register response.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::{ChipAddress, Registers};
+    /// use bm1397_protocol::response::{Response, ResponseType};
+    ///
+    /// let resp = Response::parse(&[0xAA,0x55,0x13,0x97,0x18,0x00,0x00,0x00,0x06]).unwrap();
+    /// assert_eq!(resp.as_reg().unwrap().chip_addr, 0);
+    /// assert!(resp.as_job().is_none());
+    /// assert_eq!(
+    ///     resp.into_reg().unwrap().register,
+    ///     Registers::ChipAddress(ChipAddress::default())
+    /// );
+    /// ```
+    pub const fn as_reg(&self) -> Option<&RegisterResponse> {
+        match self {
+            ResponseType::Reg(r) => Some(r),
+            ResponseType::Job(_) => None,
+        }
+    }
+
+    /// Convert into the `JobResponse` if this is a job response.
+    pub fn into_job(self) -> Option<JobResponse> {
+        match self {
+            ResponseType::Job(j) => Some(j),
+            ResponseType::Reg(_) => None,
+        }
+    }
+
+    /// Convert into the `RegisterResponse` if this is a register response.
+    pub fn into_reg(self) -> Option<RegisterResponse> {
+        match self {
+            ResponseType::Reg(r) => Some(r),
+            ResponseType::Job(_) => None,
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for ResponseType {
+    type Error = Error;
+
+    /// Parse a response from a slice of raw bytes.
+    ///
+    /// Same as `Response::parse` but the slice length is checked first.
+    ///
+    /// ## Return
+    /// - `Err(Error::InvalidLength(usize))` with the slice length if it is not `RESPONSE_LEN` (9) bytes.
+    /// - Otherwise the same as `Response::parse`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::Error;
+    /// use bm1397_protocol::response::ResponseType;
+    ///
+    /// let frame = [0xAA, 0x55, 0x13, 0x97, 0x18, 0x00, 0x00, 0x00, 0x06, 0x00];
+    ///
+    /// let resp = ResponseType::try_from(&frame[..9]);
+    /// assert!(matches!(resp, Ok(ResponseType::Reg(_))));
+    ///
+    /// // Error::InvalidLength
+    /// let resp = ResponseType::try_from(&frame[..8]);
+    /// assert_eq!(resp.unwrap_err(), Error::InvalidLength(8));
+    ///
+    /// let resp = ResponseType::try_from(&frame[..]);
+    /// assert_eq!(resp.unwrap_err(), Error::InvalidLength(10));
+    /// ```
+    fn try_from(data: &[u8]) -> Result<Self, Self::Error> {
+        let data: &[u8; RESPONSE_LEN] = data
+            .try_into()
+            .map_err(|_| Error::InvalidLength(data.len()))?;
+        Response::parse(data)
+    }
+}
+
+/// # Chain Report
+///
+/// Collect the `RegisterResponse`s of a full chain read, keyed by `chip_addr`,
+/// to display them as a per-chip, per-register dump, typically for a bug report.
+///
+/// For each chip, the registers are sorted by address and a new response for an already
+/// collected register replace the previous one.
+///
+/// ## Example
+///
+/// ```
+/// use bm1397_protocol::register::{ChipAddress, MiscControl, Registers};
+/// use bm1397_protocol::response::{ChainReport, RegisterResponse};
+///
+/// let report: ChainReport = [
+///     RegisterResponse { chip_addr: 0x08, register: Registers::MiscControl(MiscControl::DEFAULT) },
+///     RegisterResponse { chip_addr: 0x00, register: Registers::ChipAddress(ChipAddress::DEFAULT) },
+///     RegisterResponse { chip_addr: 0x08, register: Registers::ChipAddress(ChipAddress::DEFAULT) },
+/// ]
+/// .into_iter()
+/// .collect();
+/// assert_eq!(report.chip_count(), 2);
+/// assert_eq!(report.registers(0x08).len(), 2);
+/// assert_eq!(
+///     format!("{}", report),
+///     format!(
+///         "Chip 0x00\n  {}\nChip 0x08\n  {}\n  {}\n",
+///         ChipAddress::DEFAULT,
+///         ChipAddress::DEFAULT,
+///         MiscControl::DEFAULT
+///     )
+/// );
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Default)]
+pub struct ChainReport {
+    chips: alloc::collections::BTreeMap<u8, alloc::vec::Vec<Registers>>,
+}
+
+#[cfg(feature = "alloc")]
+impl ChainReport {
+    /// Create an empty report.
+    pub const fn new() -> Self {
+        Self {
+            chips: alloc::collections::BTreeMap::new(),
+        }
+    }
+
+    /// Add a register response to the report.
+    pub fn push(&mut self, resp: RegisterResponse) {
+        let regs = self.chips.entry(resp.chip_addr).or_default();
+        let addr = resp.register.addr();
+        match regs.binary_search_by_key(&addr, |r| r.addr()) {
+            Ok(i) => regs[i] = resp.register,
+            Err(i) => regs.insert(i, resp.register),
+        }
+    }
+
+    /// Get the number of chips with at least one register in the report.
+    pub fn chip_count(&self) -> usize {
+        self.chips.len()
+    }
+
+    /// Get the registers collected for the chip at `chip_addr`, sorted by address.
+    pub fn registers(&self, chip_addr: u8) -> &[Registers] {
+        self.chips
+            .get(&chip_addr)
+            .map_or(&[], |regs| regs.as_slice())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromIterator<RegisterResponse> for ChainReport {
+    fn from_iter<I: IntoIterator<Item = RegisterResponse>>(iter: I) -> Self {
+        let mut report = Self::new();
+        for resp in iter {
+            report.push(resp);
+        }
+        report
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ::core::fmt::Display for ChainReport {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        for (chip_addr, regs) in &self.chips {
+            writeln!(f, "Chip {:#04x}", chip_addr)?;
+            for reg in regs {
+                writeln!(f, "  {}", reg)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// # Response Accumulator
+///
+/// Rebuild the response frames from a byte stream delivered one or a few bytes at a time,
+/// typically by an interrupt or DMA driven UART.
+///
+/// Bytes are discarded until the `[0xAA, 0x55]` preamble is found, then accumulated until
+/// a full `RESPONSE_LEN` frame is available and parsed with `Response::parse`.
+/// If the frame CRC5 is not valid, the accumulator resync on the next preamble found
+/// in the discarded frame, so a lost byte only cost one response.
+///
+/// ## Example
+///
+/// ```
+/// use bm1397_protocol::response::{ResponseAccumulator, ResponseType};
+///
+/// let stream = [
+///     0x18, 0x00, // end of a previous frame
+///     0xAA, 0x55, 0x13, 0x97, 0x18, 0x00, 0x00, 0x00, 0x06,
+///     0xAA, 0x55, 0x97, 0xC3, 0x28, 0xB6, 0x01, 0x63, 0x9C,
+/// ];
+/// let mut acc = ResponseAccumulator::new();
+/// let mut resps = stream.iter().filter_map(|b| acc.push(*b));
+/// assert!(matches!(resps.next(), Some(Ok(ResponseType::Reg(_)))));
+/// assert!(matches!(resps.next(), Some(Ok(ResponseType::Job(_)))));
+/// assert!(resps.next().is_none());
+/// ```
+#[derive(Debug, Clone)]
+pub struct ResponseAccumulator {
+    buf: [u8; RESPONSE_LEN],
+    len: usize,
+}
+
+impl Default for ResponseAccumulator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResponseAccumulator {
+    /// Create an empty accumulator, waiting for a preamble.
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; RESPONSE_LEN],
+            len: 0,
+        }
+    }
+
+    /// Discard the bytes accumulated so far, to wait for a new preamble.
+    pub fn reset(&mut self) {
+        self.len = 0;
+    }
+
+    /// Feed one byte from the stream.
+    ///
+    /// ## Return
+    /// - `None` if the frame is not complete yet.
+    /// - `Some(Ok(ResponseType))` when a full frame was accumulated.
+    /// - `Some(Err(Error))` when a full frame was accumulated but can not be parsed,
+    ///   see `Response::parse`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::Error;
+    /// use bm1397_protocol::response::{ResponseAccumulator, ResponseType};
+    ///
+    /// let mut acc = ResponseAccumulator::new();
+    /// // a frame with a lost byte, directly followed by a valid one
+    /// let stream = [
+    ///     0xAA, 0x55, 0x13, 0x97, 0x18, 0x00, 0x00, 0x06,
+    ///     0xAA, 0x55, 0x13, 0x97, 0x18, 0x00, 0x00, 0x00, 0x06,
+    /// ];
+    /// let resps: Vec<_> = stream.iter().filter_map(|b| acc.push(*b)).collect();
+    /// assert_eq!(resps.len(), 2);
+    /// assert!(matches!(resps[0], Err(Error::InvalidCrc5 { .. })));
+    /// assert!(matches!(resps[1], Ok(ResponseType::Reg(_))));
+    /// ```
+    pub fn push(&mut self, byte: u8) -> Option<Result<ResponseType, Error>> {
+        match (self.len, byte) {
+            (0, 0xAA) | (1, 0x55) => {}
+            (0, _) => return None,
+            (1, 0xAA) => return None,
+            (1, _) => {
+                self.len = 0;
+                return None;
+            }
+            _ => {}
+        }
+        self.buf[self.len] = byte;
+        self.len += 1;
+        if self.len < RESPONSE_LEN {
+            return None;
+        }
+        let resp = Response::parse(&self.buf);
+        match resp {
+            Err(Error::InvalidCrc5 { .. }) => self.resync(),
+            _ => self.len = 0,
+        }
+        Some(resp)
+    }
+
+    /// Keep the bytes from the next preamble (or trailing `0xAA`) of the buffer, if any.
+    fn resync(&mut self) {
+        let next = (1..self.len)
+            .find(|&i| self.buf[i] == 0xAA && (i + 1 == self.len || self.buf[i + 1] == 0x55));
+        match next {
+            Some(i) => {
+                self.buf.copy_within(i..self.len, 0);
+                self.len -= i;
+            }
+            None => self.len = 0,
+        }
+    }
+}
+
 pub struct Response;
 
 impl Response {
+    /// Bit mask of the CRC5 in the last byte of a response.
+    pub(crate) const CRC5_MASK: u8 = 0x1f;
+
+    /// # CRC OK
+    ///
+    /// Check the CRC5 of a response frame.
+    ///
+    /// The CRC5 (without final xor) has a null residue : computed over the frame
+    /// after the preamble *including* the received CRC5 (the 5 least significant bits of
+    /// the last byte), it gives `0x00` if and only if the received CRC5 is the one of the
+    /// rest of the frame. So there is no need to extract and compare the CRC5.
+    ///
+    /// The preamble is not checked, see `Response::has_preamble`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::response::Response;
+    ///
+    /// assert!(Response::crc_ok(&[0xAA, 0x55, 0x13, 0x97, 0x18, 0x00, 0x00, 0x00, 0x06]));
+    /// assert!(!Response::crc_ok(&[0xAA, 0x55, 0x13, 0x97, 0x18, 0x00, 0x00, 0x00, 0x07]));
+    /// ```
+    pub const fn crc_ok(data: &[u8; RESPONSE_LEN]) -> bool {
+        crc5(data.split_at(2).1) == 0x00
+    }
+
+    /// Compute the CRC5 expected in the last byte of a response.
+    ///
+    /// The CRC5 only cover the 5 least significant bits of the last byte,
+    /// so search the one giving a null residue.
+    pub(crate) const fn expected_crc5(data: &[u8; RESPONSE_LEN]) -> u8 {
+        let mut frame = *data;
+        let mut crc = 0;
+        while crc <= Self::CRC5_MASK {
+            frame[8] = (data[8] & !Self::CRC5_MASK) | crc;
+            if Self::crc_ok(&frame) {
+                return crc;
+            }
+            crc += 1;
+        }
+        0
+    }
+
+    /// # Encode Register Response
+    ///
+    /// Build the response of the chip at `chip_addr` to a read of `reg`, with a valid CRC5.
+    ///
+    /// This is the inverse of `Response::parse` for a `RegisterResponse`,
+    /// to emulate a chip.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::register::ChipAddress;
+    /// use bm1397_protocol::response::Response;
+    ///
+    /// let frame = Response::encode_reg(0, &ChipAddress::DEFAULT);
+    /// assert_eq!(frame, [0xAA, 0x55, 0x13, 0x97, 0x18, 0x00, 0x00, 0x00, 0x06]);
+    /// ```
+    pub fn encode_reg(chip_addr: u8, reg: &impl Register) -> [u8; RESPONSE_LEN] {
+        let [b0, b1, b2, b3] = reg.val().to_be_bytes();
+        let mut data = [0xAA, 0x55, b0, b1, b2, b3, chip_addr, reg.addr(), 0x00];
+        data[8] = Self::expected_crc5(&data);
+        data
+    }
+
+    /// # Encode Job Response
+    ///
+    /// Build the response of a chip finding `nonce` for the job `job_id` and the midstate
+    /// `midstate_id`, with a valid CRC5.
+    ///
+    /// This is the inverse of `Response::parse` for a `JobResponse`, to emulate a chip.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::response::Response;
+    ///
+    /// let frame = Response::encode_job(0x97C3_28B6, 0x63, 1);
+    /// assert_eq!(frame, [0xAA, 0x55, 0x97, 0xC3, 0x28, 0xB6, 0x01, 0x63, 0x9C]);
+    /// ```
+    pub const fn encode_job(nonce: u32, job_id: u8, midstate_id: u8) -> [u8; RESPONSE_LEN] {
+        let [b0, b1, b2, b3] = nonce.to_be_bytes();
+        let mut data = [0xAA, 0x55, b0, b1, b2, b3, midstate_id, job_id, 0x80];
+        data[8] |= Self::expected_crc5(&data);
+        data
+    }
+
     /// # Parse Response
     ///
     /// Parse raw bytes from RO signal of BM1397.
     ///
-    /// The packet must have a lenght of 9 bytes.
+    /// The packet must have a lenght of `RESPONSE_LEN` (9) bytes.
     ///
     /// ## Return
     /// - `Err(Error::InvalidPreamble)` if it first 2 bytes are not `[0xAA, 0x55]`.
-    /// - `Err(Error::InvalidCrc)` if the CRC5 is not valid.
+    /// - `Err(Error::InvalidCrc5 { expected, found })` if the CRC5 is not valid.
     /// - `Ok(ResponseType::Reg(r))` with the `RegisterResponse`.
     /// - `Err(Error::UnknownRegister(u8))` with the register address if it do not match a known `Registers`.
     /// - `Ok(ResponseType::Job(j))` with the `JobResponse`.
@@ -59,10 +708,10 @@ impl Response {
     /// assert!(resp.is_err());
     /// assert_eq!(resp.unwrap_err(), Error::InvalidPreamble);
     ///
-    /// // Error::InvalidCrc
+    /// // Error::InvalidCrc5
     /// let resp = Response::parse(&[0xAA,0x55,0x13,0x97,0x18,0x00,0x00,0x00,0x00]);
     /// assert!(resp.is_err());
-    /// assert_eq!(resp.unwrap_err(), Error::InvalidCrc);
+    /// assert_eq!(resp.unwrap_err(), Error::InvalidCrc5 { expected: 0x06, found: 0x00 });
     ///
     /// // ChipAddress == 0x13971800
     /// let resp = Response::parse(&[0xAA,0x55,0x13,0x97,0x18,0x00,0x00,0x00,0x06]);
@@ -92,14 +741,155 @@ impl Response {
     ///     _ => panic!(),
     /// };
     /// ```
-    pub fn parse(data: &[u8; 9]) -> Result<ResponseType, Error> {
-        if data[0] != 0xAA || data[1] != 0x55 {
+    pub fn parse(data: &[u8; RESPONSE_LEN]) -> Result<ResponseType, Error> {
+        let resp = Self::decode(data);
+        trace!("parse({:02X?}): {:?}", data, resp);
+        resp
+    }
+
+    /// # Parse Response Prefix
+    ///
+    /// Parse the first response of a stream buffer, and return it with the leftover bytes
+    /// following it, so the buffer can be drained in a loop.
+    ///
+    /// Any byte before the first `[0xAA, 0x55]` preamble is skipped, so a corrupted or
+    /// partial frame at the start of `buf` does not desync the following ones.
+    ///
+    /// The leftover bytes are also returned on error, so the loop can go on after a
+    /// corrupted frame.
+    ///
+    /// ## Return
+    /// - `Err((Error::InvalidPreamble, &[u8]))` if there is no preamble in `buf`, with its
+    ///   last byte as leftover if it can be the start of a preamble.
+    /// - `Err((Error::InvalidLength(usize), &[u8]))` with the number of bytes from the
+    ///   preamble if the frame is not complete yet, the leftover starting at the preamble.
+    /// - Otherwise the same as `Response::parse` for the `RESPONSE_LEN` bytes from the
+    ///   preamble, the leftover starting after the preamble on error, to resync on the
+    ///   next frame.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::Error;
+    /// use bm1397_protocol::response::{Response, ResponseType};
+    ///
+    /// let buf = [
+    ///     0x18, 0x00, // end of a previous frame
+    ///     0xAA, 0x55, 0x13, 0x97, 0x18, 0x00, 0x00, 0x00, 0x06,
+    ///     0xAA, 0x55, 0x97, 0xC3, 0x28, 0xB6, 0x01, 0x63, 0x9C,
+    ///     0xAA, 0x55, 0x13, // start of a next frame
+    /// ];
+    /// let (resp, rest) = Response::parse_prefix(&buf).unwrap();
+    /// assert!(matches!(resp, ResponseType::Reg(_)));
+    /// let (resp, rest) = Response::parse_prefix(rest).unwrap();
+    /// assert!(matches!(resp, ResponseType::Job(_)));
+    /// assert_eq!(rest, [0xAA, 0x55, 0x13]);
+    /// assert_eq!(Response::parse_prefix(rest).unwrap_err(), (Error::InvalidLength(3), rest));
+    /// assert_eq!(
+    ///     Response::parse_prefix(&[0x55, 0xAA]).unwrap_err(),
+    ///     (Error::InvalidPreamble, &[0xAA][..])
+    /// );
+    ///
+    /// // drain a buffer with a corrupted frame in the middle
+    /// let buf = [
+    ///     0xAA, 0x55, 0x13, 0x97, 0x18, 0x00, 0x00, 0x00, 0x06,
+    ///     0xAA, 0x55, 0x13, 0x97, 0x18, 0x00, 0x00, 0x00, 0x07, // bad CRC5
+    ///     0xAA, 0x55, 0x97, 0xC3, 0x28, 0xB6, 0x01, 0x63, 0x9C,
+    /// ];
+    /// let mut rest = &buf[..];
+    /// let (mut ok, mut err) = (0, 0);
+    /// loop {
+    ///     match Response::parse_prefix(rest) {
+    ///         Ok((_, r)) => {
+    ///             ok += 1;
+    ///             rest = r;
+    ///         }
+    ///         Err((Error::InvalidPreamble | Error::InvalidLength(_), r)) => {
+    ///             rest = r;
+    ///             break;
+    ///         }
+    ///         Err((_, r)) => {
+    ///             err += 1;
+    ///             rest = r;
+    ///         }
+    ///     }
+    /// }
+    /// assert_eq!((ok, err), (2, 1));
+    /// assert!(rest.is_empty());
+    /// ```
+    pub fn parse_prefix(buf: &[u8]) -> PrefixResult<'_> {
+        let Some(start) = Self::preamble_offset(buf) else {
+            let rest = match buf.last() {
+                Some(&b) if b == RESPONSE_PREAMBLE[0] => &buf[buf.len() - 1..],
+                _ => &buf[buf.len()..],
+            };
+            return Err((Error::InvalidPreamble, rest));
+        };
+        let buf = &buf[start..];
+        let Some(data) = buf
+            .get(..RESPONSE_LEN)
+            .and_then(|d| <&[u8; RESPONSE_LEN]>::try_from(d).ok())
+        else {
+            return Err((Error::InvalidLength(buf.len()), buf));
+        };
+        match Self::parse(data) {
+            Ok(resp) => Ok((resp, &buf[RESPONSE_LEN..])),
+            Err(e) => Err((e, &buf[RESPONSE_PREAMBLE.len()..])),
+        }
+    }
+
+    /// # Has Preamble
+    ///
+    /// Check if `buf` start with the `[0xAA, 0x55]` response preamble, without parsing
+    /// the rest of the frame.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::response::Response;
+    ///
+    /// assert!(Response::has_preamble(&[0xAA, 0x55, 0x13]));
+    /// assert!(!Response::has_preamble(&[0x55, 0xAA, 0x13]));
+    /// assert!(!Response::has_preamble(&[0xAA]));
+    /// ```
+    pub const fn has_preamble(buf: &[u8]) -> bool {
+        buf.len() >= 2 && buf[0] == RESPONSE_PREAMBLE[0] && buf[1] == RESPONSE_PREAMBLE[1]
+    }
+
+    /// # Preamble Offset
+    ///
+    /// Get the offset of the first `[0xAA, 0x55]` response preamble in `buf`, to realign
+    /// a reader on the next frame boundary after a desync.
+    ///
+    /// This returns `None` if there is no full preamble in `buf`.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use bm1397_protocol::response::Response;
+    ///
+    /// // a frame with a lost byte, followed by a valid one
+    /// let buf = [0xAA, 0x55, 0x13, 0x97, 0x18, 0x00, 0x00, 0x06, 0xAA, 0x55, 0x13];
+    /// assert_eq!(Response::preamble_offset(&buf), Some(0));
+    /// assert_eq!(Response::preamble_offset(&buf[1..]), Some(7));
+    /// assert_eq!(Response::preamble_offset(&buf[9..]), None);
+    /// ```
+    pub fn preamble_offset(buf: &[u8]) -> Option<usize> {
+        buf.windows(2).position(|w| w == RESPONSE_PREAMBLE)
+    }
+
+    /// Decode the raw bytes of a response, see `Response::parse`.
+    fn decode(data: &[u8; RESPONSE_LEN]) -> Result<ResponseType, Error> {
+        if !Self::has_preamble(data) {
             return Err(Error::InvalidPreamble);
         }
-        if crc5(&data[2..9]) != 0x00 {
-            return Err(Error::InvalidCrc);
+        if !Self::crc_ok(data) {
+            return Err(Error::InvalidCrc5 {
+                expected: Self::expected_crc5(data),
+                found: data[8] & Self::CRC5_MASK,
+            });
         }
-        let reg_val = u32::from_be_bytes(data[2..6].try_into().unwrap());
+        let reg_val = u32::from_be_bytes([data[2], data[3], data[4], data[5]]);
         if data[8] & 0x80 == 0x80 {
             return Ok(ResponseType::Job(JobResponse {
                 nonce: reg_val,
@@ -202,7 +992,7 @@ impl Response {
     /// assert_eq!(Response::nonce2core_id(0x12345678), 0x12);
     /// ```
     pub fn nonce2core_id(nonce: u32) -> usize {
-        (nonce >> 24) as usize
+        ((nonce & NONCE_CORE_ID_MASK) >> NONCE_CORE_ID_OFFSET) as usize
     }
 
     /// Extract the Chip Address from a Nonce value.
@@ -227,7 +1017,65 @@ impl Response {
     /// assert_eq!(Response::nonce2chip_addr(0x12365678, 3), 54);
     /// ```
     pub fn nonce2chip_addr(nonce: u32, chip_interval: usize) -> u8 {
-        let bits = ((nonce >> 16) & 0xFF) as usize;
+        let bits = ((nonce & NONCE_CHIP_ADDR_MASK) >> NONCE_CHIP_ADDR_OFFSET) as usize;
         (bits - bits % chip_interval) as u8
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Test the null residue of the CRC5 over known good frames,
+    /// and that any single bit flip is detected.
+    #[test]
+    fn crc5_residue_is_zero() {
+        let frames = [
+            // ChipAddress of a BM1397
+            [0xAA, 0x55, 0x13, 0x97, 0x18, 0x00, 0x00, 0x00, 0x06],
+            // Nonce
+            [0xAA, 0x55, 0x97, 0xC3, 0x28, 0xB6, 0x01, 0x63, 0x9C],
+            Response::encode_reg(0x40, &crate::register::MiscControl::DEFAULT),
+            Response::encode_job(0x1234_5678, 0x2A, 3),
+        ];
+        for frame in frames {
+            assert_eq!(crc5(&frame[2..]), 0x00);
+            assert!(Response::crc_ok(&frame));
+            for byte in 2..RESPONSE_LEN {
+                for bit in 0..8 {
+                    let mut bad = frame;
+                    bad[byte] ^= 1 << bit;
+                    assert!(!Response::crc_ok(&bad), "{bad:02x?}");
+                }
+            }
+        }
+    }
+
+    /// Test that `Response::parse` never panic, whatever the register address,
+    /// the job flag and the value, with a valid CRC5 or not.
+    #[test]
+    fn parse_is_total() {
+        for addr in 0..=0xFF {
+            for last in 0..=0xFF {
+                for val in [0x00, 0x5A, 0xFF] {
+                    for mut data in [
+                        [0xAA, 0x55, val, val, val, val, val, addr, last],
+                        [0xAA, 0x55, val, val, val, val, addr, val, last],
+                    ] {
+                        let _ = Response::parse(&data);
+                        data[8] = (last & !Response::CRC5_MASK) | Response::expected_crc5(&data);
+                        assert!(!matches!(
+                            Response::parse(&data),
+                            Err(Error::InvalidCrc5 { .. })
+                        ));
+                    }
+                }
+            }
+        }
+        for preamble in 0..=0xFFFF_u16 {
+            let [p0, p1] = preamble.to_be_bytes();
+            let data = [p0, p1, 0x13, 0x97, 0x18, 0x00, 0x00, 0x00, 0x06];
+            let _ = Response::parse(&data);
+        }
+    }
+}
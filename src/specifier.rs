@@ -16,15 +16,31 @@ pub enum BaudrateClockSelect {
     /// Baudrate base clock is PLL3.
     Pll3 = 1,
 }
+/// Convert the `BCK_SEL` bit state (`true` if set) to a `BaudrateClockSelect`.
+///
+/// # Example
+///
+/// ```
+/// use bm1397_protocol::specifier::BaudrateClockSelect;
+///
+/// assert_eq!(BaudrateClockSelect::from(false), BaudrateClockSelect::Clki);
+/// assert_eq!(BaudrateClockSelect::from(true), BaudrateClockSelect::Pll3);
+/// ```
 impl From<bool> for BaudrateClockSelect {
     fn from(val: bool) -> BaudrateClockSelect {
         if val {
-            BaudrateClockSelect::Clki
-        } else {
             BaudrateClockSelect::Pll3
+        } else {
+            BaudrateClockSelect::Clki
         }
     }
 }
+/// Convert a `BaudrateClockSelect` to the `BCK_SEL` bit state (`true` if set).
+impl From<BaudrateClockSelect> for bool {
+    fn from(val: BaudrateClockSelect) -> bool {
+        val == BaudrateClockSelect::Pll3
+    }
+}
 impl From<BaudrateClockSelect> for u8 {
     fn from(val: BaudrateClockSelect) -> u8 {
         val as u8
@@ -40,11 +56,12 @@ impl From<BaudrateClockSelect> for u8 {
 /// [`ClockOrderControl0::set_clock_select`]: crate::register::ClockOrderControl0::set_clock_select
 /// [`ClockOrderControl1::clock_select`]: crate::register::ClockOrderControl1::clock_select
 /// [`ClockOrderControl1::set_clock_select`]: crate::register::ClockOrderControl1::set_clock_select
-#[derive(Copy, Clone, Eq, PartialEq, Debug, PartialOrd, Ord, Hash)]
+#[derive(Copy, Clone, Eq, PartialEq, Debug, PartialOrd, Ord, Hash, Default)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 #[repr(u8)]
 pub enum ClockSelect {
     /// Default.
+    #[default]
     Default = 0b0000,
 }
 impl ClockSelect {
@@ -73,11 +90,6 @@ impl From<ClockSelect> for u8 {
         val as u8
     }
 }
-impl Default for ClockSelect {
-    fn default() -> Self {
-        Self::Default
-    }
-}
 impl TryFrom<u8> for ClockSelect {
     type Error = u8;
     fn try_from(val: u8) -> Result<Self, u8> {
@@ -156,3 +168,65 @@ impl TryFrom<u8> for ProcessMonitorSelect {
         }
     }
 }
+
+/// Chip Variant.
+///
+/// The Bitmain mining ASIC family, identified by the `CHIP_ID` field of `ChipAddress`.
+/// This crate only drive the BM1397, the other variants are listed so a generic driver
+/// can recognize (and refuse) them.
+///
+/// This is used by [`ChipAddress::variant`] method.
+///
+/// [`ChipAddress::variant`]: crate::register::ChipAddress::variant
+#[derive(Copy, Clone, Eq, PartialEq, Debug, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[repr(u16)]
+pub enum ChipVariant {
+    /// BM1387.
+    BM1387 = 0x1387,
+    /// BM1397, the chip driven by this crate.
+    BM1397 = 0x1397,
+    /// BM1366.
+    BM1366 = 0x1366,
+    /// BM1368.
+    BM1368 = 0x1368,
+    /// BM1370.
+    BM1370 = 0x1370,
+}
+impl ChipVariant {
+    /// Convert a raw `CHIP_ID` to an `ChipVariant`.
+    ///
+    /// Chip ids that do not correspond to a known ChipVariant will be returned in the
+    /// `Err` variant of the result.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use bm1397_protocol::specifier::ChipVariant;
+    ///
+    /// assert_eq!(ChipVariant::from_raw(0x1397), Ok(ChipVariant::BM1397));
+    /// assert_eq!(ChipVariant::from_raw(0x1366), Ok(ChipVariant::BM1366));
+    /// assert_eq!(ChipVariant::from_raw(0x1234), Err(0x1234));
+    /// ```
+    pub const fn from_raw(val: u16) -> Result<Self, u16> {
+        match val {
+            x if x == ChipVariant::BM1387 as u16 => Ok(ChipVariant::BM1387),
+            x if x == ChipVariant::BM1397 as u16 => Ok(ChipVariant::BM1397),
+            x if x == ChipVariant::BM1366 as u16 => Ok(ChipVariant::BM1366),
+            x if x == ChipVariant::BM1368 as u16 => Ok(ChipVariant::BM1368),
+            x if x == ChipVariant::BM1370 as u16 => Ok(ChipVariant::BM1370),
+            _ => Err(val),
+        }
+    }
+}
+impl From<ChipVariant> for u16 {
+    fn from(val: ChipVariant) -> u16 {
+        val as u16
+    }
+}
+impl TryFrom<u16> for ChipVariant {
+    type Error = u16;
+    fn try_from(val: u16) -> Result<Self, u16> {
+        Self::from_raw(val)
+    }
+}